@@ -1,4 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use folds::num::{KahanSum, NeumaierSum, PairwiseSum, Product, Sum};
 use folds::Fold;
 
 fn sum_and_product_std(xs: &[u32]) -> (u32, u32) {
@@ -8,9 +9,7 @@ fn sum_and_product_std(xs: &[u32]) -> (u32, u32) {
 }
 
 fn sum_and_product_fused(xs: &[u32]) -> (u32, u32) {
-    let sum = folds::from_fn(0_u32, u32::wrapping_add);
-    let prod = folds::from_fn(1_u32, u32::wrapping_mul);
-    let mut fold = sum.try_zip(prod);
+    let mut fold = Sum::wrapping().try_zip(Product::wrapping());
     fold.fold(xs.iter().copied())
 }
 
@@ -26,5 +25,25 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, criterion_benchmark);
+pub fn float_sums(c: &mut Criterion) {
+    let xs: Vec<f64> = (1..=10_000_000).map(|i| 1.0 / f64::from(i)).collect();
+
+    c.bench_function("float sum, std iterator fold", |b| {
+        b.iter(|| black_box(&xs).iter().sum::<f64>());
+    });
+
+    c.bench_function("float sum, Kahan", |b| {
+        b.iter(|| KahanSum::new().fold(black_box(&xs).iter().copied()));
+    });
+
+    c.bench_function("float sum, Neumaier", |b| {
+        b.iter(|| NeumaierSum::new().fold(black_box(&xs).iter().copied()));
+    });
+
+    c.bench_function("float sum, pairwise", |b| {
+        b.iter(|| PairwiseSum::new().fold(black_box(&xs).iter().copied()));
+    });
+}
+
+criterion_group!(benches, criterion_benchmark, float_sums);
 criterion_main!(benches);
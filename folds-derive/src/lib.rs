@@ -0,0 +1,174 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, Path, Token};
+
+#[proc_macro_derive(Fold, attributes(fold))]
+pub fn derive_fold(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    if !input.generics.params.is_empty() || input.generics.where_clause.is_some() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`#[derive(Fold)]` does not support generic structs",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &data.fields,
+                    "`#[derive(Fold)]` requires a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`#[derive(Fold)]` can only be applied to structs",
+            ))
+        }
+    };
+    let Some(first) = fields.first() else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`#[derive(Fold)]` requires at least one field",
+        ));
+    };
+
+    let attrs = parse_attrs(input)?;
+    let derives = &attrs.derives;
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let acc_name = format_ident!("{name}Accumulator");
+    let out_name = format_ident!("{name}Output");
+
+    let names: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+    let vises: Vec<_> = fields.iter().map(|field| &field.vis).collect();
+    let tys: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let first_ty = &first.ty;
+
+    let merge = attrs.merge.then(|| expand_merge(name, &acc_name, &names));
+
+    Ok(quote! {
+        #[derive(#(#derives),*)]
+        #vis struct #acc_name {
+            #(#vises #names: <#tys as ::folds::Fold>::Accumulator,)*
+        }
+
+        #[derive(#(#derives),*)]
+        #vis struct #out_name {
+            #(#vises #names: <#tys as ::folds::Fold>::Output,)*
+        }
+
+        impl ::folds::Fold for #name {
+            type Accumulator = #acc_name;
+            type Output = #out_name;
+            type Error = <#first_ty as ::folds::Fold>::Error;
+            type Element = <#first_ty as ::folds::Fold>::Element;
+
+            fn init(&mut self) -> Self::Accumulator {
+                #acc_name {
+                    #(#names: ::folds::Fold::init(&mut self.#names),)*
+                }
+            }
+
+            fn try_init(
+                &mut self,
+            ) -> ::core::result::Result<Self::Accumulator, Self::Error> {
+                ::core::result::Result::Ok(#acc_name {
+                    #(#names: ::folds::Fold::try_init(&mut self.#names)?,)*
+                })
+            }
+
+            fn try_step(
+                &mut self,
+                acc: Self::Accumulator,
+                elem: Self::Element,
+            ) -> ::core::result::Result<Self::Accumulator, Self::Error> {
+                ::core::result::Result::Ok(#acc_name {
+                    #(#names: if ::folds::Fold::is_done(&self.#names, &acc.#names) {
+                        acc.#names
+                    } else {
+                        ::folds::Fold::try_step(
+                            &mut self.#names,
+                            acc.#names,
+                            ::core::clone::Clone::clone(&elem),
+                        )?
+                    },)*
+                })
+            }
+
+            fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+                #out_name {
+                    #(#names: ::folds::Fold::finish(&mut self.#names, acc.#names),)*
+                }
+            }
+
+            fn is_done(&self, acc: &Self::Accumulator) -> bool {
+                true #(&& ::folds::Fold::is_done(&self.#names, &acc.#names))*
+            }
+        }
+
+        #merge
+    })
+}
+
+fn expand_merge(name: &Ident, acc_name: &Ident, names: &[&Option<Ident>]) -> TokenStream2 {
+    quote! {
+        impl ::folds::Merge for #name {
+            fn try_merge(
+                &mut self,
+                acc1: Self::Accumulator,
+                acc2: Self::Accumulator,
+            ) -> ::core::result::Result<Self::Accumulator, Self::Error> {
+                ::core::result::Result::Ok(#acc_name {
+                    #(#names: ::folds::Merge::try_merge(
+                        &mut self.#names,
+                        acc1.#names,
+                        acc2.#names,
+                    )?,)*
+                })
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Attrs {
+    derives: Vec<Path>,
+    merge: bool,
+}
+
+fn parse_attrs(input: &DeriveInput) -> Result<Attrs, Error> {
+    let mut attrs = Attrs::default();
+    for attr in &input.attrs {
+        if !attr.path().is_ident("fold") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("derive") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let derives = Punctuated::<Path, Token![,]>::parse_terminated(&content)?;
+                attrs.derives.extend(derives);
+                Ok(())
+            } else if meta.path.is_ident("merge") {
+                attrs.merge = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `fold` attribute"))
+            }
+        })?;
+    }
+    Ok(attrs)
+}
@@ -0,0 +1,75 @@
+use folds::num::Sum;
+use folds::{from_fn, from_try_fn, Fold, FromFn, FromTryFn, Merge};
+
+type Step = fn(u32, u32) -> u32;
+type TryStep = fn(u32, u32) -> Result<u32, ()>;
+
+#[derive(Fold)]
+#[fold(derive(Debug, PartialEq))]
+struct Stats {
+    count: FromFn<u32, u32, Step>,
+    sum: FromFn<u32, u32, Step>,
+    max: FromFn<u32, u32, Step>,
+}
+
+#[derive(Fold)]
+#[fold(derive(Debug, PartialEq))]
+struct CheckedStats {
+    sum: FromTryFn<u32, u32, TryStep>,
+    prod: FromTryFn<u32, u32, TryStep>,
+}
+
+#[test]
+fn named_fields() {
+    let mut stats = Stats {
+        count: from_fn(0, |n, _| n + 1),
+        sum: from_fn(0, u32::wrapping_add),
+        max: from_fn(0, u32::max),
+    };
+    let xs = &[3, 1, 4, 1, 5];
+    assert_eq!(
+        stats.fold(xs.iter().copied()),
+        StatsOutput {
+            count: 5,
+            sum: 14,
+            max: 5,
+        }
+    );
+}
+
+#[test]
+fn short_circuits() {
+    let mut stats = CheckedStats {
+        sum: from_try_fn(0, |x: u32, y| x.checked_add(y).ok_or(())),
+        prod: from_try_fn(1, |x: u32, y| x.checked_mul(y).ok_or(())),
+    };
+    let xs = &[1, 2, 3, 4];
+    assert_eq!(
+        stats.try_fold(xs.iter().copied()),
+        Ok(CheckedStatsOutput { sum: 10, prod: 24 })
+    );
+    assert_eq!(stats.try_fold([u32::MAX, 2].into_iter()), Err(()));
+}
+
+#[derive(Clone, Fold)]
+#[fold(derive(Debug, PartialEq), merge)]
+struct Sums {
+    total: Sum<u64>,
+    again: Sum<u64>,
+}
+
+#[test]
+fn merge() {
+    let xs: Vec<u64> = (1..=100).collect();
+    assert_eq!(
+        Sums {
+            total: Sum::wrapping(),
+            again: Sum::wrapping(),
+        }
+        .fold_par(&xs),
+        SumsOutput {
+            total: 5050,
+            again: 5050,
+        }
+    );
+}
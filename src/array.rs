@@ -0,0 +1,187 @@
+use core::convert::Infallible;
+
+use crate::{Fold, Merge, ZipAll};
+
+impl<F, const N: usize> Fold for [F; N]
+where
+    F: Fold,
+    F::Element: Clone,
+{
+    type Accumulator = [F::Accumulator; N];
+    type Output = [F::Output; N];
+    type Error = F::Error;
+    type Element = F::Element;
+
+    fn init(&mut self) -> Self::Accumulator { self.each_mut().map(F::init) }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> {
+        let mut error = None;
+        let acc = self.each_mut().map(|fold| {
+            if error.is_some() {
+                return None;
+            }
+            fold.try_init().map_err(|err| error = Some(err)).ok()
+        });
+        if let Some(err) = error {
+            return Err(err);
+        }
+        Ok(acc.map(Option::unwrap))
+    }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let mut folds = self.iter_mut();
+        let mut error = None;
+        let acc = acc.map(|acc| {
+            let fold = folds.next()?;
+            if error.is_some() {
+                None
+            } else if fold.is_done(&acc) {
+                Some(acc)
+            } else {
+                fold.try_step(acc, elem.clone())
+                    .map_err(|err| error = Some(err))
+                    .ok()
+            }
+        });
+        if let Some(err) = error {
+            return Err(err);
+        }
+        Ok(acc.map(Option::unwrap))
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        let mut folds = self.iter_mut();
+        acc.map(|acc| folds.next().unwrap().finish(acc))
+    }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool {
+        self.iter().zip(acc).all(|(fold, acc)| fold.is_done(acc))
+    }
+}
+
+impl<F, const N: usize> Merge for [F; N]
+where
+    F: Merge,
+    F::Element: Clone,
+{
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let mut folds = self.iter_mut();
+        let mut rhs = acc2.into_iter();
+        let mut error = None;
+        let acc = acc1.map(|acc1| {
+            let fold = folds.next()?;
+            let acc2 = rhs.next()?;
+            if error.is_some() {
+                return None;
+            }
+            (fold.try_merge(acc1, acc2))
+                .map_err(|err| error = Some(err))
+                .ok()
+        });
+        if let Some(err) = error {
+            return Err(err);
+        }
+        Ok(acc.map(Option::unwrap))
+    }
+}
+
+impl<F, const N: usize> Fold for ZipAll<[F; N]>
+where
+    F: Fold,
+    F::Element: Clone,
+{
+    type Accumulator = [Result<F::Accumulator, F::Error>; N];
+    type Output = [Result<F::Output, F::Error>; N];
+    type Error = Infallible;
+    type Element = F::Element;
+
+    fn init(&mut self) -> Self::Accumulator { self.folds.each_mut().map(F::try_init) }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let mut folds = self.folds.iter_mut();
+        Ok(acc.map(|acc| {
+            let fold = folds.next().unwrap();
+            match acc {
+                Ok(acc) if !fold.is_done(&acc) => fold.try_step(acc, elem.clone()),
+                res => res,
+            }
+        }))
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        let mut folds = self.folds.iter_mut();
+        acc.map(|acc| {
+            let fold = folds.next().unwrap();
+            acc.map(|acc| fold.finish(acc))
+        })
+    }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool {
+        (self.folds.iter().zip(acc))
+            .all(|(fold, acc)| acc.as_ref().map_or(true, |acc| fold.is_done(acc)))
+    }
+}
+
+impl<F, const N: usize> Merge for ZipAll<[F; N]>
+where
+    F: Merge,
+    F::Element: Clone,
+{
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let mut folds = self.folds.iter_mut();
+        let mut rhs = acc2.into_iter();
+        Ok(acc1.map(|acc1| {
+            let fold = folds.next().unwrap();
+            let acc2 = rhs.next().unwrap();
+            acc1.and_then(|acc1| fold.try_merge(acc1, acc2?))
+        }))
+    }
+}
+
+#[test]
+fn thresholds() {
+    use crate::{from_fn, zip_all};
+
+    fn count_above(threshold: u32) -> impl Fold<Element = u32, Output = u32, Error = Infallible> {
+        from_fn(0_u32, |n, _| n + 1).filter(move |x| *x > threshold)
+    }
+
+    let mut fold = [1, 2, 3].map(count_above);
+    let xs = &[1, 2, 3, 4];
+    assert_eq!(fold.fold(xs.iter().copied()), [3, 2, 1]);
+
+    let mut fold = zip_all([1, 2, 3].map(count_above));
+    assert_eq!(fold.fold(xs.iter().copied()), [Ok(3), Ok(2), Ok(1)]);
+}
+
+#[test]
+fn checked_sums() {
+    use crate::{from_try_fn, zip_all};
+
+    let sum = |init| from_try_fn(init, move |x: u8, y| x.checked_add(y).ok_or(init));
+    let xs = &[100, 100];
+    assert_eq!(
+        [sum(0), sum(50), sum(60)].try_fold(xs.iter().copied()),
+        Err(60)
+    );
+    assert_eq!(
+        zip_all([sum(0), sum(50), sum(60)]).fold(xs.iter().copied()),
+        [Ok(200), Ok(250), Err(60)]
+    );
+}
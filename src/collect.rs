@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+use core::convert::Infallible;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::{Fold, Merge};
+
+stateless_fold!(Collect);
+
+impl<T> Fold for Collect<T> {
+    type Accumulator = Vec<T>;
+    type Output = Vec<T>;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator { Vec::new() }
+
+    fn try_step(
+        &mut self,
+        mut acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        acc.push(elem);
+        Ok(acc)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
+}
+
+impl<T> Merge for Collect<T> {
+    fn try_merge(
+        &mut self,
+        mut acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        acc1.extend(acc2);
+        Ok(acc1)
+    }
+}
+
+/// Runs `fold` separately over the elements of each key.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct GroupBy<F, Fun> {
+    fold: F,
+    key: Fun,
+}
+
+#[cfg(feature = "std")]
+impl<F, Fun> GroupBy<F, Fun> {
+    #[must_use]
+    pub const fn new(fold: F, key: Fun) -> Self { Self { fold, key } }
+}
+
+#[cfg(feature = "std")]
+impl<F, K, Fun> Fold for GroupBy<F, Fun>
+where
+    F: Fold,
+    K: Eq + Hash,
+    Fun: FnMut(&F::Element) -> K,
+{
+    type Accumulator = HashMap<K, F::Accumulator>;
+    type Output = HashMap<K, F::Output>;
+    type Error = F::Error;
+    type Element = F::Element;
+
+    fn init(&mut self) -> Self::Accumulator { HashMap::new() }
+
+    fn try_step(
+        &mut self,
+        mut acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let key = (self.key)(&elem);
+        let group = match acc.remove(&key) {
+            Some(group) => group,
+            None => self.fold.try_init()?,
+        };
+        let group = if self.fold.is_done(&group) {
+            group
+        } else {
+            self.fold.try_step(group, elem)?
+        };
+        acc.insert(key, group);
+        Ok(acc)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        (acc.into_iter())
+            .map(|(key, group)| (key, self.fold.finish(group)))
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, K, Fun> Merge for GroupBy<F, Fun>
+where
+    F: Merge,
+    K: Eq + Hash,
+    Fun: FnMut(&F::Element) -> K,
+{
+    fn try_merge(
+        &mut self,
+        mut acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        for (key, group2) in acc2 {
+            let group = match acc1.remove(&key) {
+                Some(group1) => self.fold.try_merge(group1, group2)?,
+                None => group2,
+            };
+            acc1.insert(key, group);
+        }
+        Ok(acc1)
+    }
+}
+
+#[test]
+fn collect() {
+    let mut fold = Collect::new();
+    assert_eq!(fold.fold([3, 1, 2].into_iter()), [3, 1, 2]);
+    let chunks: [&[u32]; 3] = [&[3], &[], &[1, 2]];
+    assert_eq!(crate::fold_chunks(&mut fold, chunks), [3, 1, 2]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn group_by() {
+    use crate::num::{Overflow, Sum};
+
+    let xs: Vec<u32> = (1..=10).collect();
+    let mut fold = GroupBy::new(Sum::wrapping(), |x: &u32| x % 3);
+    let sums = HashMap::from([(0, 18), (1, 22), (2, 15)]);
+    assert_eq!(fold.fold(xs.iter().copied()), sums);
+    assert_eq!(crate::fold_chunks(&mut fold, xs.chunks(4)), sums);
+
+    let mut fold = GroupBy::new(Sum::checked(), |x: &u8| x % 2);
+    assert_eq!(fold.try_fold([200, 1, 100].into_iter()), Err(Overflow));
+    assert_eq!(
+        fold.try_fold([200, 1, 101].into_iter()),
+        Ok(HashMap::from([(0, 200), (1, 102)]))
+    );
+}
@@ -0,0 +1,114 @@
+use alloc::boxed::Box;
+use core::any::Any;
+
+use crate::Fold;
+
+pub trait DynFold<Elem, Out, Error> {
+    fn dyn_init(&mut self) -> Box<dyn Any>;
+
+    fn dyn_try_init(&mut self) -> Result<Box<dyn Any>, Error>;
+
+    fn dyn_try_step(&mut self, acc: Box<dyn Any>, elem: Elem) -> Result<Box<dyn Any>, Error>;
+
+    fn dyn_finish(&mut self, acc: Box<dyn Any>) -> Out;
+
+    fn dyn_is_done(&self, acc: &dyn Any) -> bool;
+}
+
+// The erased accumulator is stored as an `Option` so that `dyn_try_step` can
+// move it out and back in without reallocating the box.
+fn downcast_mut<Acc: 'static>(acc: &mut dyn Any) -> &mut Option<Acc> {
+    acc.downcast_mut()
+        .expect("accumulator was not created by this fold")
+}
+
+fn downcast_ref<Acc: 'static>(acc: &dyn Any) -> &Acc {
+    acc.downcast_ref::<Option<Acc>>()
+        .and_then(Option::as_ref)
+        .expect("accumulator was not created by this fold")
+}
+
+impl<F> DynFold<F::Element, F::Output, F::Error> for F
+where
+    F: Fold,
+    F::Accumulator: 'static,
+{
+    fn dyn_init(&mut self) -> Box<dyn Any> { Box::new(Some(self.init())) }
+
+    fn dyn_try_init(&mut self) -> Result<Box<dyn Any>, F::Error> {
+        let acc = self.try_init()?;
+        Ok(Box::new(Some(acc)))
+    }
+
+    fn dyn_try_step(
+        &mut self,
+        mut acc: Box<dyn Any>,
+        elem: F::Element,
+    ) -> Result<Box<dyn Any>, F::Error> {
+        let slot = downcast_mut::<F::Accumulator>(acc.as_mut());
+        let inner = slot
+            .take()
+            .expect("accumulator was not created by this fold");
+        *slot = Some(self.try_step(inner, elem)?);
+        Ok(acc)
+    }
+
+    fn dyn_finish(&mut self, mut acc: Box<dyn Any>) -> F::Output {
+        let inner = downcast_mut::<F::Accumulator>(acc.as_mut())
+            .take()
+            .expect("accumulator was not created by this fold");
+        self.finish(inner)
+    }
+
+    fn dyn_is_done(&self, acc: &dyn Any) -> bool { self.is_done(downcast_ref(acc)) }
+}
+
+impl<Elem, Out, Error> Fold for dyn DynFold<Elem, Out, Error> + '_ {
+    type Accumulator = Box<dyn Any>;
+    type Output = Out;
+    type Error = Error;
+    type Element = Elem;
+
+    fn init(&mut self) -> Self::Accumulator { self.dyn_init() }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> { self.dyn_try_init() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.dyn_try_step(acc, elem)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { self.dyn_finish(acc) }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool { self.dyn_is_done(acc.as_ref()) }
+}
+
+#[test]
+fn heterogeneous() {
+    use core::convert::Infallible;
+    use std::vec;
+    use std::vec::Vec;
+
+    use crate::{from_fn, IteratorExt};
+
+    let folds: Vec<Box<dyn DynFold<u32, u32, Infallible>>> = vec![
+        from_fn(0, u32::wrapping_add).boxed(),
+        from_fn(0, |n, _| n + 1).filter(|x| x % 2 == 0).boxed(),
+        from_fn(0, u32::max).take(2).boxed(),
+    ];
+    let xs = [3, 1, 4, 1, 5];
+    assert_eq!(xs.into_iter().fold_with(folds), vec![14, 1, 3]);
+}
+
+#[test]
+fn zip_erased() {
+    use crate::{from_try_fn, IteratorExt};
+
+    let sum = from_try_fn(0_u8, |x: u8, y| x.checked_add(y).ok_or(()));
+    let prod = from_try_fn(1_u8, |x: u8, y| x.checked_mul(y).ok_or(()));
+    let fold = sum.boxed().try_zip(prod.boxed());
+    assert_eq!([1, 2, 3].into_iter().try_fold_with(fold), Ok((6, 6)));
+}
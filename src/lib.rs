@@ -1,7 +1,80 @@
-#![feature(never_type)]
+#![no_std]
+#![cfg_attr(feature = "nightly", feature(never_type))]
 
-use std::convert::Infallible;
-use std::marker::PhantomData;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt;
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use core::num::NonZeroUsize;
+use core::ops::ControlFlow;
+#[cfg(feature = "std")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "std")]
+use std::thread;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+// Declares a fold with no state besides its element type `T`.
+macro_rules! stateless_fold {
+    ($Name:ident) => {
+        pub struct $Name<T> {
+            phantom: ::core::marker::PhantomData<fn(T) -> T>,
+        }
+
+        impl<T> $Name<T> {
+            #[must_use]
+            pub const fn new() -> Self {
+                Self {
+                    phantom: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        impl<T> Default for $Name<T> {
+            fn default() -> Self { Self::new() }
+        }
+
+        impl<T> Clone for $Name<T> {
+            fn clone(&self) -> Self { Self::new() }
+        }
+    };
+}
+
+mod array;
+#[cfg(feature = "alloc")]
+pub mod collect;
+#[cfg(feature = "alloc")]
+mod dyn_fold;
+pub mod num;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "alloc")]
+pub mod quantile;
+mod running;
+mod scan;
+#[cfg(feature = "alloc")]
+pub mod sketch;
+pub mod stats;
+mod tuple;
+#[cfg(feature = "alloc")]
+mod vec;
+
+#[cfg(feature = "alloc")]
+pub use dyn_fold::DynFold;
+#[cfg(feature = "derive")]
+pub use folds_derive::Fold;
+#[cfg(feature = "rayon")]
+pub use par::ParallelIteratorExt;
+pub use running::Running;
+pub use scan::{Scan, TryScan};
 
 pub trait Uninhabited {
     fn absurd<A>(self) -> A;
@@ -11,31 +84,94 @@ impl Uninhabited for Infallible {
     fn absurd<A>(self) -> A { match self {} }
 }
 
+#[cfg(feature = "nightly")]
 impl Uninhabited for ! {
     fn absurd<A>(self) -> A { match self {} }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<T> Either<T, T> {
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Left(x) | Self::Right(x) => x,
+        }
+    }
+}
+
+impl<L: fmt::Display, R: fmt::Display> fmt::Display for Either<L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Left(x) => x.fmt(f),
+            Self::Right(x) => x.fmt(f),
+        }
+    }
+}
+
+impl<L: Error, R: Error> Error for Either<L, R> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Left(x) => x.source(),
+            Self::Right(x) => x.source(),
+        }
+    }
+}
+
 pub trait Fold {
     type Accumulator;
+    type Output;
     type Error;
     type Element;
 
+    /// Folds whose initialisation can fail should override [`Fold::try_init`]
+    /// and may panic here.
     fn init(&mut self) -> Self::Accumulator;
 
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> { Ok(self.init()) }
+
     fn try_step(
         &mut self,
         acc: Self::Accumulator,
         elem: Self::Element,
     ) -> Result<Self::Accumulator, Self::Error>;
 
-    fn try_fold(
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output;
+
+    fn is_done(&self, _acc: &Self::Accumulator) -> bool { false }
+
+    fn try_accumulate(
         &mut self,
+        acc: Self::Accumulator,
         mut iter: impl Iterator<Item = Self::Element>,
     ) -> Result<Self::Accumulator, Self::Error> {
-        iter.try_fold(self.init(), |acc, elem| self.try_step(acc, elem))
+        if self.is_done(&acc) {
+            return Ok(acc);
+        }
+        let flow = iter.try_fold(acc, |acc, elem| match self.try_step(acc, elem) {
+            Ok(acc) if self.is_done(&acc) => ControlFlow::Break(Ok(acc)),
+            Ok(acc) => ControlFlow::Continue(acc),
+            Err(err) => ControlFlow::Break(Err(err)),
+        });
+        match flow {
+            ControlFlow::Continue(acc) => Ok(acc),
+            ControlFlow::Break(res) => res,
+        }
+    }
+
+    fn try_fold(
+        &mut self,
+        iter: impl Iterator<Item = Self::Element>,
+    ) -> Result<Self::Output, Self::Error> {
+        let acc = self.try_init()?;
+        let acc = self.try_accumulate(acc, iter)?;
+        Ok(self.finish(acc))
     }
 
-    fn fold(&mut self, iter: impl Iterator<Item = Self::Element>) -> Self::Accumulator
+    fn fold(&mut self, iter: impl Iterator<Item = Self::Element>) -> Self::Output
     where
         Self::Error: Uninhabited,
     {
@@ -45,6 +181,23 @@ pub trait Fold {
         }
     }
 
+    fn try_scan<I>(&mut self, iter: I) -> TryScan<'_, Self, I>
+    where
+        I: Iterator<Item = Self::Element>,
+        Self::Accumulator: Clone,
+    {
+        TryScan::new(self, iter)
+    }
+
+    fn scan<I>(&mut self, iter: I) -> Scan<'_, Self, I>
+    where
+        I: Iterator<Item = Self::Element>,
+        Self::Accumulator: Clone,
+        Self::Error: Uninhabited,
+    {
+        Scan::new(self, iter)
+    }
+
     fn zip<F2>(self, other: F2) -> Zip<Self, F2>
     where
         Self: Sized,
@@ -64,8 +217,280 @@ pub trait Fold {
             fold2: other,
         }
     }
+
+    fn try_zip_either<F2>(self, other: F2) -> TryZipEither<Self, F2>
+    where
+        Self: Sized,
+    {
+        TryZipEither {
+            fold1: self,
+            fold2: other,
+        }
+    }
+
+    fn premap<Elem, Fun>(self, fun: Fun) -> Premap<Self, Elem, Fun>
+    where
+        Self: Sized,
+        Fun: FnMut(Elem) -> Self::Element,
+    {
+        Premap {
+            fold: self,
+            fun,
+            phantom: PhantomData,
+        }
+    }
+
+    fn try_premap<Elem, Fun>(self, fun: Fun) -> TryPremap<Self, Elem, Fun>
+    where
+        Self: Sized,
+        Fun: FnMut(Elem) -> Result<Self::Element, Self::Error>,
+    {
+        TryPremap {
+            fold: self,
+            fun,
+            phantom: PhantomData,
+        }
+    }
+
+    fn map_output<Out, Fun>(self, fun: Fun) -> MapOutput<Self, Fun>
+    where
+        Self: Sized,
+        Fun: FnMut(Self::Output) -> Out,
+    {
+        MapOutput { fold: self, fun }
+    }
+
+    fn map_err<Error, Fun>(self, fun: Fun) -> MapErr<Self, Fun>
+    where
+        Self: Sized,
+        Fun: FnMut(Self::Error) -> Error,
+    {
+        MapErr { fold: self, fun }
+    }
+
+    fn filter<Pred>(self, pred: Pred) -> Filter<Self, Pred>
+    where
+        Self: Sized,
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        Filter { fold: self, pred }
+    }
+
+    fn filter_map<Elem, Fun>(self, fun: Fun) -> FilterMap<Self, Elem, Fun>
+    where
+        Self: Sized,
+        Fun: FnMut(Elem) -> Option<Self::Element>,
+    {
+        FilterMap {
+            fold: self,
+            fun,
+            phantom: PhantomData,
+        }
+    }
+
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take { fold: self, n }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn boxed<'a>(self) -> Box<dyn DynFold<Self::Element, Self::Output, Self::Error> + 'a>
+    where
+        Self: Sized + 'a,
+        Self::Accumulator: 'static,
+    {
+        Box::new(self)
+    }
+
+    fn running(self) -> Running<Self>
+    where
+        Self: Sized,
+    {
+        Running::new(self)
+    }
+}
+
+pub trait Merge: Fold {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error>;
+
+    fn merge(&mut self, acc1: Self::Accumulator, acc2: Self::Accumulator) -> Self::Accumulator
+    where
+        Self::Error: Uninhabited,
+    {
+        match self.try_merge(acc1, acc2) {
+            Ok(acc) => acc,
+            Err(err) => err.absurd(),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn try_fold_par(&mut self, elems: &[Self::Element]) -> Result<Self::Output, Self::Error>
+    where
+        Self: Clone + Send,
+        Self::Accumulator: Send,
+        Self::Error: Send,
+        Self::Element: Clone + Sync,
+    {
+        let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        let chunk_size = elems.len().div_ceil(threads).max(1);
+        // The lowest index of a failed chunk. Later chunks can stop early, but
+        // earlier ones must run to their own first error, if any, so that the
+        // error returned is the one `try_fold` would return.
+        let failed = AtomicUsize::new(usize::MAX);
+        let results: Vec<_> = thread::scope(|scope| {
+            let handles: Vec<_> = (elems.chunks(chunk_size).enumerate())
+                .map(|(index, chunk)| {
+                    let mut fold = self.clone();
+                    let failed = &failed;
+                    scope.spawn(move || {
+                        let iter = chunk.iter().cloned();
+                        let iter = iter.take_while(|_| index < failed.load(Ordering::Relaxed));
+                        let res = (fold.try_init()).and_then(|acc| fold.try_accumulate(acc, iter));
+                        if res.is_err() {
+                            failed.fetch_min(index, Ordering::Relaxed);
+                        }
+                        res
+                    })
+                })
+                .collect();
+            (handles.into_iter())
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        let mut acc = self.try_init()?;
+        for res in results {
+            acc = self.try_merge(acc, res?)?;
+        }
+        Ok(self.finish(acc))
+    }
+
+    #[cfg(feature = "std")]
+    fn fold_par(&mut self, elems: &[Self::Element]) -> Self::Output
+    where
+        Self: Clone + Send,
+        Self::Accumulator: Send,
+        Self::Error: Send + Uninhabited,
+        Self::Element: Clone + Sync,
+    {
+        match self.try_fold_par(elems) {
+            Ok(acc) => acc,
+            Err(err) => err.absurd(),
+        }
+    }
+}
+
+pub trait IteratorExt: Iterator {
+    fn try_fold_with<F>(self, mut fold: F) -> Result<F::Output, F::Error>
+    where
+        Self: Sized,
+        F: Fold<Element = Self::Item>,
+    {
+        fold.try_fold(self)
+    }
+
+    fn fold_with<F>(self, mut fold: F) -> F::Output
+    where
+        Self: Sized,
+        F: Fold<Element = Self::Item>,
+        F::Error: Uninhabited,
+    {
+        fold.fold(self)
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+impl<F: Fold + ?Sized> Fold for &mut F {
+    type Accumulator = F::Accumulator;
+    type Output = F::Output;
+    type Error = F::Error;
+    type Element = F::Element;
+
+    fn init(&mut self) -> Self::Accumulator { (**self).init() }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> { (**self).try_init() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        (**self).try_step(acc, elem)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { (**self).finish(acc) }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool { (**self).is_done(acc) }
+
+    fn try_accumulate(
+        &mut self,
+        acc: Self::Accumulator,
+        iter: impl Iterator<Item = Self::Element>,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        (**self).try_accumulate(acc, iter)
+    }
+}
+
+impl<F: Merge + ?Sized> Merge for &mut F {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        (**self).try_merge(acc1, acc2)
+    }
 }
 
+#[cfg(feature = "alloc")]
+impl<F: Fold + ?Sized> Fold for Box<F> {
+    type Accumulator = F::Accumulator;
+    type Output = F::Output;
+    type Error = F::Error;
+    type Element = F::Element;
+
+    fn init(&mut self) -> Self::Accumulator { (**self).init() }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> { (**self).try_init() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        (**self).try_step(acc, elem)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { (**self).finish(acc) }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool { (**self).is_done(acc) }
+
+    fn try_accumulate(
+        &mut self,
+        acc: Self::Accumulator,
+        iter: impl Iterator<Item = Self::Element>,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        (**self).try_accumulate(acc, iter)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: Merge + ?Sized> Merge for Box<F> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        (**self).try_merge(acc1, acc2)
+    }
+}
+
+#[derive(Clone)]
 pub struct Zip<Fold1, Fold2> {
     fold1: Fold1,
     fold2: Fold2,
@@ -81,10 +506,14 @@ where
         Result<Fold1::Accumulator, Fold1::Error>,
         Result<Fold2::Accumulator, Fold2::Error>,
     );
+    type Output = (
+        Result<Fold1::Output, Fold1::Error>,
+        Result<Fold2::Output, Fold2::Error>,
+    );
     type Error = Infallible;
     type Element = Fold1::Element;
 
-    fn init(&mut self) -> Self::Accumulator { (Ok(self.fold1.init()), Ok(self.fold2.init())) }
+    fn init(&mut self) -> Self::Accumulator { (self.fold1.try_init(), self.fold2.try_init()) }
 
     fn try_step(
         &mut self,
@@ -92,12 +521,51 @@ where
         elem: Self::Element,
     ) -> Result<Self::Accumulator, Self::Error> {
         let (acc1, acc2) = acc;
-        let res1 = acc1.and_then(|acc1| self.fold1.try_step(acc1, elem.clone()));
-        let res2 = acc2.and_then(|acc2| self.fold2.try_step(acc2, elem.clone()));
+        let res1 = match acc1 {
+            Ok(acc1) if !self.fold1.is_done(&acc1) => self.fold1.try_step(acc1, elem.clone()),
+            res1 => res1,
+        };
+        let res2 = match acc2 {
+            Ok(acc2) if !self.fold2.is_done(&acc2) => self.fold2.try_step(acc2, elem),
+            res2 => res2,
+        };
+        Ok((res1, res2))
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        let (acc1, acc2) = acc;
+        let out1 = acc1.map(|acc1| self.fold1.finish(acc1));
+        let out2 = acc2.map(|acc2| self.fold2.finish(acc2));
+        (out1, out2)
+    }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool {
+        let (acc1, acc2) = acc;
+        let done1 = acc1.as_ref().map_or(true, |acc1| self.fold1.is_done(acc1));
+        let done2 = acc2.as_ref().map_or(true, |acc2| self.fold2.is_done(acc2));
+        done1 && done2
+    }
+}
+
+impl<Fold1, Fold2> Merge for Zip<Fold1, Fold2>
+where
+    Fold1: Merge,
+    Fold2: Merge<Element = Fold1::Element>,
+    Fold1::Element: Clone,
+{
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let ((acc1_1, acc1_2), (acc2_1, acc2_2)) = (acc1, acc2);
+        let res1 = acc1_1.and_then(|acc1| self.fold1.try_merge(acc1, acc2_1?));
+        let res2 = acc1_2.and_then(|acc1| self.fold2.try_merge(acc1, acc2_2?));
         Ok((res1, res2))
     }
 }
 
+#[derive(Clone)]
 pub struct TryZip<Fold1, Fold2> {
     fold1: Fold1,
     fold2: Fold2,
@@ -110,81 +578,556 @@ where
     Fold1::Element: Clone,
 {
     type Accumulator = (Fold1::Accumulator, Fold2::Accumulator);
+    type Output = (Fold1::Output, Fold2::Output);
     type Error = Fold1::Error;
     type Element = Fold1::Element;
 
     fn init(&mut self) -> Self::Accumulator { (self.fold1.init(), self.fold2.init()) }
 
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> {
+        Ok((self.fold1.try_init()?, self.fold2.try_init()?))
+    }
+
     fn try_step(
         &mut self,
         acc: Self::Accumulator,
         elem: Self::Element,
     ) -> Result<Self::Accumulator, Self::Error> {
         let (acc1, acc2) = acc;
-        let res1 = self.fold1.try_step(acc1, elem.clone())?;
-        let res2 = self.fold2.try_step(acc2, elem)?;
+        let res1 = if self.fold1.is_done(&acc1) {
+            acc1
+        } else {
+            self.fold1.try_step(acc1, elem.clone())?
+        };
+        let res2 = if self.fold2.is_done(&acc2) {
+            acc2
+        } else {
+            self.fold2.try_step(acc2, elem)?
+        };
         Ok((res1, res2))
     }
-}
 
-pub struct FromFn<A, E, F> {
-    acc: A,
-    fun: F,
-    phantom: PhantomData<E>,
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        let (acc1, acc2) = acc;
+        (self.fold1.finish(acc1), self.fold2.finish(acc2))
+    }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool {
+        let (acc1, acc2) = acc;
+        self.fold1.is_done(acc1) && self.fold2.is_done(acc2)
+    }
 }
 
-impl<Acc, Elem, F> Fold for FromFn<Acc, Elem, F>
+impl<Fold1, Fold2> Merge for TryZip<Fold1, Fold2>
 where
-    Acc: Clone,
-    F: Fn(Acc, Elem) -> Acc,
+    Fold1: Merge,
+    Fold2: Merge<Element = Fold1::Element, Error = Fold1::Error>,
+    Fold1::Element: Clone,
 {
-    type Accumulator = Acc;
-    type Error = Infallible;
-    type Element = Elem;
-
-    fn init(&mut self) -> Self::Accumulator { self.acc.clone() }
-
-    fn try_step(
+    fn try_merge(
         &mut self,
-        acc: Self::Accumulator,
-        elem: Self::Element,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
     ) -> Result<Self::Accumulator, Self::Error> {
-        Ok((self.fun)(acc, elem))
-    }
-}
-
-pub const fn from_fn<A, E, F>(acc: A, f: F) -> FromFn<A, E, F> {
-    FromFn {
-        acc,
-        fun: f,
-        phantom: PhantomData,
+        let ((acc1_1, acc1_2), (acc2_1, acc2_2)) = (acc1, acc2);
+        let res1 = self.fold1.try_merge(acc1_1, acc2_1)?;
+        let res2 = self.fold2.try_merge(acc1_2, acc2_2)?;
+        Ok((res1, res2))
     }
 }
 
-pub struct FromTryFn<Acc, Elem, Fun> {
-    acc: Acc,
-    fun: Fun,
-    phantom: PhantomData<Elem>,
+#[derive(Clone)]
+pub struct TryZipEither<Fold1, Fold2> {
+    fold1: Fold1,
+    fold2: Fold2,
 }
 
-impl<Acc, Error, Elem, Fun> Fold for FromTryFn<Acc, Elem, Fun>
+impl<Fold1, Fold2> Fold for TryZipEither<Fold1, Fold2>
 where
-    Acc: Clone,
-    Fun: Fn(Acc, Elem) -> Result<Acc, Error>,
+    Fold1: Fold,
+    Fold2: Fold<Element = Fold1::Element>,
+    Fold1::Element: Clone,
 {
-    type Accumulator = Acc;
-    type Error = Error;
-    type Element = Elem;
+    type Accumulator = (Fold1::Accumulator, Fold2::Accumulator);
+    type Output = (Fold1::Output, Fold2::Output);
+    type Error = Either<Fold1::Error, Fold2::Error>;
+    type Element = Fold1::Element;
 
-    fn init(&mut self) -> Self::Accumulator { self.acc.clone() }
+    fn init(&mut self) -> Self::Accumulator { (self.fold1.init(), self.fold2.init()) }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> {
+        let acc1 = self.fold1.try_init().map_err(Either::Left)?;
+        let acc2 = self.fold2.try_init().map_err(Either::Right)?;
+        Ok((acc1, acc2))
+    }
 
     fn try_step(
         &mut self,
         acc: Self::Accumulator,
         elem: Self::Element,
     ) -> Result<Self::Accumulator, Self::Error> {
-        (self.fun)(acc, elem)
+        let (acc1, acc2) = acc;
+        let res1 = if self.fold1.is_done(&acc1) {
+            acc1
+        } else {
+            self.fold1
+                .try_step(acc1, elem.clone())
+                .map_err(Either::Left)?
+        };
+        let res2 = if self.fold2.is_done(&acc2) {
+            acc2
+        } else {
+            self.fold2.try_step(acc2, elem).map_err(Either::Right)?
+        };
+        Ok((res1, res2))
     }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        let (acc1, acc2) = acc;
+        (self.fold1.finish(acc1), self.fold2.finish(acc2))
+    }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool {
+        let (acc1, acc2) = acc;
+        self.fold1.is_done(acc1) && self.fold2.is_done(acc2)
+    }
+}
+
+impl<Fold1, Fold2> Merge for TryZipEither<Fold1, Fold2>
+where
+    Fold1: Merge,
+    Fold2: Merge<Element = Fold1::Element>,
+    Fold1::Element: Clone,
+{
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let ((acc1_1, acc1_2), (acc2_1, acc2_2)) = (acc1, acc2);
+        let res1 = self.fold1.try_merge(acc1_1, acc2_1).map_err(Either::Left)?;
+        let res2 = self
+            .fold2
+            .try_merge(acc1_2, acc2_2)
+            .map_err(Either::Right)?;
+        Ok((res1, res2))
+    }
+}
+
+#[derive(Clone)]
+pub struct ZipAll<Folds> {
+    folds: Folds,
+}
+
+pub const fn zip_all<Folds>(folds: Folds) -> ZipAll<Folds> { ZipAll { folds } }
+
+#[derive(Clone)]
+pub struct Premap<F, Elem, Fun> {
+    fold: F,
+    fun: Fun,
+    phantom: PhantomData<Elem>,
+}
+
+impl<F, Elem, Fun> Fold for Premap<F, Elem, Fun>
+where
+    F: Fold,
+    Fun: FnMut(Elem) -> F::Element,
+{
+    type Accumulator = F::Accumulator;
+    type Output = F::Output;
+    type Error = F::Error;
+    type Element = Elem;
+
+    fn init(&mut self) -> Self::Accumulator { self.fold.init() }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> { self.fold.try_init() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.fold.try_step(acc, (self.fun)(elem))
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { self.fold.finish(acc) }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool { self.fold.is_done(acc) }
+}
+
+impl<F, Elem, Fun> Merge for Premap<F, Elem, Fun>
+where
+    F: Merge,
+    Fun: FnMut(Elem) -> F::Element,
+{
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.fold.try_merge(acc1, acc2)
+    }
+}
+
+#[derive(Clone)]
+pub struct TryPremap<F, Elem, Fun> {
+    fold: F,
+    fun: Fun,
+    phantom: PhantomData<Elem>,
+}
+
+impl<F, Elem, Fun> Fold for TryPremap<F, Elem, Fun>
+where
+    F: Fold,
+    Fun: FnMut(Elem) -> Result<F::Element, F::Error>,
+{
+    type Accumulator = F::Accumulator;
+    type Output = F::Output;
+    type Error = F::Error;
+    type Element = Elem;
+
+    fn init(&mut self) -> Self::Accumulator { self.fold.init() }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> { self.fold.try_init() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let elem = (self.fun)(elem)?;
+        self.fold.try_step(acc, elem)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { self.fold.finish(acc) }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool { self.fold.is_done(acc) }
+}
+
+impl<F, Elem, Fun> Merge for TryPremap<F, Elem, Fun>
+where
+    F: Merge,
+    Fun: FnMut(Elem) -> Result<F::Element, F::Error>,
+{
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.fold.try_merge(acc1, acc2)
+    }
+}
+
+#[derive(Clone)]
+pub struct MapOutput<F, Fun> {
+    fold: F,
+    fun: Fun,
+}
+
+impl<F, Fun, Out> Fold for MapOutput<F, Fun>
+where
+    F: Fold,
+    Fun: FnMut(F::Output) -> Out,
+{
+    type Accumulator = F::Accumulator;
+    type Output = Out;
+    type Error = F::Error;
+    type Element = F::Element;
+
+    fn init(&mut self) -> Self::Accumulator { self.fold.init() }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> { self.fold.try_init() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.fold.try_step(acc, elem)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        (self.fun)(self.fold.finish(acc))
+    }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool { self.fold.is_done(acc) }
+}
+
+impl<F, Fun, Out> Merge for MapOutput<F, Fun>
+where
+    F: Merge,
+    Fun: FnMut(F::Output) -> Out,
+{
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.fold.try_merge(acc1, acc2)
+    }
+}
+
+#[derive(Clone)]
+pub struct MapErr<F, Fun> {
+    fold: F,
+    fun: Fun,
+}
+
+impl<F, Fun, Error> Fold for MapErr<F, Fun>
+where
+    F: Fold,
+    Fun: FnMut(F::Error) -> Error,
+{
+    type Accumulator = F::Accumulator;
+    type Output = F::Output;
+    type Error = Error;
+    type Element = F::Element;
+
+    fn init(&mut self) -> Self::Accumulator { self.fold.init() }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> {
+        self.fold.try_init().map_err(&mut self.fun)
+    }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.fold.try_step(acc, elem).map_err(&mut self.fun)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { self.fold.finish(acc) }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool { self.fold.is_done(acc) }
+}
+
+impl<F, Fun, Error> Merge for MapErr<F, Fun>
+where
+    F: Merge,
+    Fun: FnMut(F::Error) -> Error,
+{
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.fold.try_merge(acc1, acc2).map_err(&mut self.fun)
+    }
+}
+
+#[derive(Clone)]
+pub struct Filter<F, Pred> {
+    fold: F,
+    pred: Pred,
+}
+
+impl<F, Pred> Fold for Filter<F, Pred>
+where
+    F: Fold,
+    Pred: FnMut(&F::Element) -> bool,
+{
+    type Accumulator = F::Accumulator;
+    type Output = F::Output;
+    type Error = F::Error;
+    type Element = F::Element;
+
+    fn init(&mut self) -> Self::Accumulator { self.fold.init() }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> { self.fold.try_init() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        if (self.pred)(&elem) {
+            self.fold.try_step(acc, elem)
+        } else {
+            Ok(acc)
+        }
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { self.fold.finish(acc) }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool { self.fold.is_done(acc) }
+}
+
+impl<F, Pred> Merge for Filter<F, Pred>
+where
+    F: Merge,
+    Pred: FnMut(&F::Element) -> bool,
+{
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.fold.try_merge(acc1, acc2)
+    }
+}
+
+#[derive(Clone)]
+pub struct FilterMap<F, Elem, Fun> {
+    fold: F,
+    fun: Fun,
+    phantom: PhantomData<Elem>,
+}
+
+impl<F, Elem, Fun> Fold for FilterMap<F, Elem, Fun>
+where
+    F: Fold,
+    Fun: FnMut(Elem) -> Option<F::Element>,
+{
+    type Accumulator = F::Accumulator;
+    type Output = F::Output;
+    type Error = F::Error;
+    type Element = Elem;
+
+    fn init(&mut self) -> Self::Accumulator { self.fold.init() }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> { self.fold.try_init() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        match (self.fun)(elem) {
+            Some(elem) => self.fold.try_step(acc, elem),
+            None => Ok(acc),
+        }
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { self.fold.finish(acc) }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool { self.fold.is_done(acc) }
+}
+
+impl<F, Elem, Fun> Merge for FilterMap<F, Elem, Fun>
+where
+    F: Merge,
+    Fun: FnMut(Elem) -> Option<F::Element>,
+{
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.fold.try_merge(acc1, acc2)
+    }
+}
+
+#[derive(Clone)]
+pub struct Take<F> {
+    fold: F,
+    n: usize,
+}
+
+impl<F> Fold for Take<F>
+where
+    F: Fold,
+{
+    type Accumulator = (usize, F::Accumulator);
+    type Output = F::Output;
+    type Error = F::Error;
+    type Element = F::Element;
+
+    fn init(&mut self) -> Self::Accumulator { (self.n, self.fold.init()) }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> {
+        Ok((self.n, self.fold.try_init()?))
+    }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let (remaining, acc) = acc;
+        match remaining.checked_sub(1) {
+            Some(remaining) => Ok((remaining, self.fold.try_step(acc, elem)?)),
+            None => Ok((remaining, acc)),
+        }
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        let (_, acc) = acc;
+        self.fold.finish(acc)
+    }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool {
+        let (remaining, acc) = acc;
+        *remaining == 0 || self.fold.is_done(acc)
+    }
+}
+
+#[derive(Clone)]
+pub struct FromFn<A, E, F> {
+    acc: A,
+    fun: F,
+    phantom: PhantomData<E>,
+}
+
+impl<Acc, Elem, F> Fold for FromFn<Acc, Elem, F>
+where
+    Acc: Clone,
+    F: FnMut(Acc, Elem) -> Acc,
+{
+    type Accumulator = Acc;
+    type Output = Acc;
+    type Error = Infallible;
+    type Element = Elem;
+
+    fn init(&mut self) -> Self::Accumulator { self.acc.clone() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok((self.fun)(acc, elem))
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
+}
+
+pub const fn from_fn<A, E, F>(acc: A, f: F) -> FromFn<A, E, F> {
+    FromFn {
+        acc,
+        fun: f,
+        phantom: PhantomData,
+    }
+}
+
+#[derive(Clone)]
+pub struct FromTryFn<Acc, Elem, Fun> {
+    acc: Acc,
+    fun: Fun,
+    phantom: PhantomData<Elem>,
+}
+
+impl<Acc, Error, Elem, Fun> Fold for FromTryFn<Acc, Elem, Fun>
+where
+    Acc: Clone,
+    Fun: FnMut(Acc, Elem) -> Result<Acc, Error>,
+{
+    type Accumulator = Acc;
+    type Output = Acc;
+    type Error = Error;
+    type Element = Elem;
+
+    fn init(&mut self) -> Self::Accumulator { self.acc.clone() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        (self.fun)(acc, elem)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
 }
 
 pub const fn from_try_fn<Acc, Elem, Fun>(acc: Acc, fun: Fun) -> FromTryFn<Acc, Elem, Fun> {
@@ -195,6 +1138,85 @@ pub const fn from_try_fn<Acc, Elem, Fun>(acc: Acc, fun: Fun) -> FromTryFn<Acc, E
     }
 }
 
+#[derive(Clone)]
+pub struct FromFns<Init, Elem, Step> {
+    init: Init,
+    step: Step,
+    phantom: PhantomData<Elem>,
+}
+
+impl<Acc, Elem, Init, Step> Fold for FromFns<Init, Elem, Step>
+where
+    Init: FnMut() -> Acc,
+    Step: FnMut(Acc, Elem) -> Acc,
+{
+    type Accumulator = Acc;
+    type Output = Acc;
+    type Error = Infallible;
+    type Element = Elem;
+
+    fn init(&mut self) -> Self::Accumulator { (self.init)() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok((self.step)(acc, elem))
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
+}
+
+pub const fn from_fns<Init, Elem, Step>(init: Init, step: Step) -> FromFns<Init, Elem, Step> {
+    FromFns {
+        init,
+        step,
+        phantom: PhantomData,
+    }
+}
+
+#[derive(Clone)]
+pub struct FromTryFns<Init, Elem, Step> {
+    init: Init,
+    step: Step,
+    phantom: PhantomData<Elem>,
+}
+
+impl<Acc, Error, Elem, Init, Step> Fold for FromTryFns<Init, Elem, Step>
+where
+    Init: FnMut() -> Acc,
+    Step: FnMut(Acc, Elem) -> Result<Acc, Error>,
+{
+    type Accumulator = Acc;
+    type Output = Acc;
+    type Error = Error;
+    type Element = Elem;
+
+    fn init(&mut self) -> Self::Accumulator { (self.init)() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        (self.step)(acc, elem)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
+}
+
+pub const fn from_try_fns<Init, Elem, Step>(
+    init: Init,
+    step: Step,
+) -> FromTryFns<Init, Elem, Step> {
+    FromTryFns {
+        init,
+        step,
+        phantom: PhantomData,
+    }
+}
+
 #[test]
 fn sum_and_product() {
     let sum = from_fn(0_u32, u32::wrapping_add);
@@ -212,3 +1234,380 @@ fn try_sum_and_product() {
     let xs = &[1, 2, 3, 4];
     assert_eq!(fold.try_fold(xs.iter().copied()), Ok((10, 24)));
 }
+
+#[test]
+fn mean() {
+    struct Mean;
+
+    impl Fold for Mean {
+        type Accumulator = (f64, u32);
+        type Output = Option<f64>;
+        type Error = Infallible;
+        type Element = f64;
+
+        fn init(&mut self) -> Self::Accumulator { (0.0, 0) }
+
+        fn try_step(
+            &mut self,
+            (sum, count): Self::Accumulator,
+            elem: Self::Element,
+        ) -> Result<Self::Accumulator, Self::Error> {
+            Ok((sum + elem, count + 1))
+        }
+
+        fn finish(&mut self, (sum, count): Self::Accumulator) -> Self::Output {
+            (count != 0).then(|| sum / f64::from(count))
+        }
+    }
+
+    let xs = &[1.0, 2.0, 3.0, 4.0];
+    assert_eq!(Mean.fold(xs.iter().copied()), Some(2.5));
+    assert_eq!(Mean.fold(core::iter::empty()), None);
+
+    let mut fold = Mean.zip(from_fn(0_u32, |n, _| n + 1));
+    assert_eq!(fold.fold(xs.iter().copied()), (Ok(Some(2.5)), Ok(4)));
+}
+
+#[test]
+fn premap_fields() {
+    #[derive(Clone)]
+    struct Item {
+        price: u32,
+        quantity: u32,
+    }
+
+    let sum_prices = from_fn(0_u32, u32::wrapping_add).premap(|item: Item| item.price);
+    let sum_quantities = from_fn(0_u32, u32::wrapping_add).premap(|item: Item| item.quantity);
+    let mut fold = sum_prices.try_zip(sum_quantities);
+    let items = [
+        Item {
+            price: 3,
+            quantity: 1,
+        },
+        Item {
+            price: 5,
+            quantity: 2,
+        },
+    ];
+    assert_eq!(fold.fold(items.into_iter()), (8, 3));
+}
+
+#[test]
+fn try_premap_parse() {
+    let sum = from_try_fn(0_u32, |x: u32, y: u32| x.checked_add(y).ok_or(()));
+    let mut fold = sum.try_premap(|s: &str| s.parse::<u32>().map_err(|_| ()));
+    assert_eq!(fold.try_fold(["1", "2", "3"].into_iter()), Ok(6));
+    assert_eq!(fold.try_fold(["1", "x", "3"].into_iter()), Err(()));
+}
+
+#[test]
+fn map_output_average() {
+    let sum = from_try_fn(0_u32, |x: u32, y: u32| x.checked_add(y).ok_or(()));
+    let count = from_fn(0_u32, |n: u32, _: u32| n + 1).map_err(|err| match err {});
+    let mut fold = sum
+        .try_zip(count)
+        .map_output(|(sum, count)| f64::from(sum) / f64::from(count));
+    let xs = &[1, 2, 3, 4];
+    assert_eq!(fold.try_fold(xs.iter().copied()), Ok(2.5));
+    assert_eq!(fold.try_fold([u32::MAX, 1].into_iter()), Err(()));
+}
+
+#[test]
+fn filter_even_and_odd() {
+    let sum_even = from_fn(0_u32, u32::wrapping_add).filter(|x| x % 2 == 0);
+    let count_odd = from_fn(0_u32, |n, _: u32| n + 1).filter(|x| x % 2 == 1);
+    let mut fold = sum_even.try_zip(count_odd);
+    let xs = &[1, 2, 3, 4, 5];
+    assert_eq!(fold.fold(xs.iter().copied()), (6, 3));
+}
+
+#[test]
+fn filter_map_parse() {
+    let mut fold = from_fn(0_u32, u32::wrapping_add).filter_map(|s: &str| s.parse().ok());
+    assert_eq!(fold.fold(["1", "x", "3"].into_iter()), 4);
+}
+
+#[test]
+fn take_stops_early() {
+    let mut pulled = 0;
+    let xs = (1..=10).inspect(|_| pulled += 1);
+    let mut fold = from_fn(0_u32, u32::wrapping_add).take(3);
+    assert_eq!(fold.fold(xs), 6);
+    assert_eq!(pulled, 3);
+}
+
+#[test]
+fn take_zero_pulls_nothing() {
+    let mut pulled = 0;
+    let xs = (1..=10).inspect(|_| pulled += 1);
+    let mut fold = from_fn(0_u32, u32::wrapping_add).take(0);
+    assert_eq!(fold.fold(xs), 0);
+    assert_eq!(pulled, 0);
+}
+
+#[test]
+fn zip_stops_when_all_done() {
+    let mut pulled = 0;
+    let xs = (1..=10).inspect(|_| pulled += 1);
+    let first = from_fn(None, |acc: Option<u32>, x| acc.or(Some(x))).take(1);
+    let sum = from_fn(0_u32, u32::wrapping_add).take(4);
+    let mut fold = first.zip(sum);
+    assert_eq!(fold.fold(xs), (Ok(Some(1)), Ok(10)));
+    assert_eq!(pulled, 4);
+
+    let mut pulled = 0;
+    let xs = (1..=10).inspect(|_| pulled += 1);
+    let first = from_fn(None, |acc: Option<u32>, x| acc.or(Some(x))).take(1);
+    let sum = from_fn(0_u32, u32::wrapping_add).take(4);
+    let mut fold = first.try_zip(sum);
+    assert_eq!(fold.fold(xs), (Some(1), 10));
+    assert_eq!(pulled, 4);
+}
+
+#[test]
+fn zip_stops_when_all_failed() {
+    let mut pulled = 0;
+    let xs = (1..=10).inspect(|_| pulled += 1);
+    let lt3 = from_try_fn((), |(), x: u32| if x < 3 { Ok(()) } else { Err(x) });
+    let lt5 = from_try_fn((), |(), x: u32| if x < 5 { Ok(()) } else { Err(x) });
+    let mut fold = lt3.zip(lt5);
+    assert_eq!(fold.fold(xs), (Err(3), Err(5)));
+    assert_eq!(pulled, 5);
+}
+
+#[test]
+fn try_zip_either_errors() {
+    let total_len = from_try_fn(0_u8, |n: u8, s: &str| {
+        u8::try_from(s.len())
+            .ok()
+            .and_then(|len| n.checked_add(len))
+            .ok_or(())
+    });
+    let sum = from_try_fn(0_u32, |n: u32, s: &str| s.parse().map(|x: u32| n + x));
+    let mut fold = total_len.try_zip_either(sum);
+    assert_eq!(fold.try_fold(["1", "22"].into_iter()), Ok((3, 23)));
+    assert!(matches!(
+        fold.try_fold(["1", "x"].into_iter()),
+        Err(Either::Right(_))
+    ));
+    let zeros = "0".repeat(200);
+    assert_eq!(
+        fold.try_fold([zeros.as_str(), zeros.as_str()].into_iter()),
+        Err(Either::Left(()))
+    );
+}
+
+#[test]
+fn fold_with_borrowed() {
+    let mut sum = from_fn(0_u32, u32::wrapping_add);
+    assert_eq!([1, 2, 3].into_iter().fold_with(&mut sum), 6);
+    assert_eq!([4, 5, 6].into_iter().fold_with(&mut sum), 15);
+
+    let mut sum = from_try_fn(0_u8, |x: u8, y| x.checked_add(y).ok_or(()));
+    assert_eq!([1, 2, 3].into_iter().try_fold_with(&mut sum), Ok(6));
+    assert_eq!([200, 100].into_iter().try_fold_with(&mut sum), Err(()));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn fold_with_boxed() {
+    use std::vec;
+    use std::vec::Vec;
+
+    type Step = fn(u32, u32) -> u32;
+
+    let folds: Vec<Box<FromFn<u32, u32, Step>>> = vec![
+        Box::new(from_fn(0, u32::wrapping_add)),
+        Box::new(from_fn(1, u32::wrapping_mul)),
+    ];
+    assert_eq!([1, 2, 3, 4].into_iter().fold_with(folds), vec![10, 24]);
+}
+
+#[test]
+fn word_counts() {
+    use std::collections::HashMap;
+
+    let mut fold = from_fns(
+        HashMap::new,
+        |mut counts: HashMap<&'static str, u32>, word: &'static str| {
+            *counts.entry(word).or_default() += 1;
+            counts
+        },
+    );
+    let counts = fold.fold("a b a c a b".split(' '));
+    assert_eq!(counts, HashMap::from([("a", 3), ("b", 2), ("c", 1)]));
+}
+
+#[test]
+fn try_from_fns_fresh_init() {
+    use std::vec;
+    use std::vec::Vec;
+
+    let mut inits = 0;
+    let mut fold = from_try_fns(
+        || {
+            inits += 1;
+            Vec::new()
+        },
+        |mut xs: Vec<u32>, x: u32| {
+            xs.push(x.checked_mul(2).ok_or(x)?);
+            Ok(xs)
+        },
+    );
+    assert_eq!(fold.try_fold([1, 2].into_iter()), Ok(vec![2, 4]));
+    assert_eq!(fold.try_fold(core::iter::once(u32::MAX)), Err(u32::MAX));
+    assert_eq!(inits, 2);
+}
+
+#[test]
+fn fn_mut_step() {
+    use std::vec::Vec;
+
+    let mut seen = Vec::new();
+    let mut fold = from_fn(0_u32, |acc, x| {
+        seen.push(x);
+        acc + x
+    });
+    assert_eq!(fold.fold([1, 2, 3].into_iter()), 6);
+    assert_eq!(seen, [1, 2, 3]);
+}
+
+#[test]
+fn try_init_propagates() {
+    use std::vec;
+    use std::vec::Vec;
+
+    struct Buffer {
+        capacity: usize,
+    }
+
+    impl Fold for Buffer {
+        type Accumulator = Vec<u32>;
+        type Output = Vec<u32>;
+        type Error = usize;
+        type Element = u32;
+
+        fn init(&mut self) -> Self::Accumulator { self.try_init().expect("buffer too large") }
+
+        fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> {
+            if self.capacity > 4 {
+                return Err(self.capacity);
+            }
+            Ok(Vec::with_capacity(self.capacity))
+        }
+
+        fn try_step(
+            &mut self,
+            mut acc: Self::Accumulator,
+            elem: Self::Element,
+        ) -> Result<Self::Accumulator, Self::Error> {
+            if acc.len() == self.capacity {
+                return Err(self.capacity);
+            }
+            acc.push(elem);
+            Ok(acc)
+        }
+
+        fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
+    }
+
+    let small = || Buffer { capacity: 2 };
+    let large = || Buffer { capacity: 8 };
+    let xs = || [1, 2].into_iter();
+    assert_eq!(small().try_fold(xs()), Ok(vec![1, 2]));
+    assert_eq!(large().try_fold(xs()), Err(8));
+    assert_eq!(large().zip(small()).fold(xs()), (Err(8), Ok(vec![1, 2])));
+    assert_eq!(small().try_zip(large()).try_fold(xs()), Err(8));
+    assert_eq!(large().running().push(1), Err(&8));
+    assert_eq!(large().try_scan(xs()).collect::<Vec<_>>(), [Err(8)]);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn never_error() {
+    let mut fold = from_try_fn(0_u32, |x: u32, y| Ok::<_, !>(x.wrapping_add(y)));
+    assert_eq!(fold.fold([1, 2, 3].into_iter()), 6);
+}
+
+// Folds each chunk separately and merges the results in order, as
+// `fold_par` would.
+#[cfg(test)]
+fn fold_chunks<'a, F>(fold: &mut F, chunks: impl IntoIterator<Item = &'a [F::Element]>) -> F::Output
+where
+    F: Merge,
+    F::Element: Clone + 'a,
+    F::Error: fmt::Debug,
+{
+    let mut acc = fold.try_init().unwrap();
+    for chunk in chunks {
+        let chunk_acc = fold.try_init().unwrap();
+        let chunk_acc = fold
+            .try_accumulate(chunk_acc, chunk.iter().cloned())
+            .unwrap();
+        acc = fold.try_merge(acc, chunk_acc).unwrap();
+    }
+    fold.finish(acc)
+}
+
+#[test]
+fn merge_chunks() {
+    use std::vec::Vec;
+
+    use crate::num::Sum;
+
+    let mut fold = Sum::wrapping().try_zip(Sum::wrapping().filter(|x| x % 2 == 0));
+    let xs: Vec<u64> = (1..=100).collect();
+    assert_eq!(
+        fold_chunks(&mut fold, <[_; 2]>::from(xs.split_at(37))),
+        (5050, 2550)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn fold_par() {
+    use std::vec::Vec;
+
+    use crate::num::Sum;
+
+    let xs: Vec<u64> = (1..=10_000).collect();
+    let mut fold = Sum::wrapping().zip(Sum::wrapping().map_output(|x| x * 2));
+    assert_eq!(fold.fold_par(&xs), (Ok(50_005_000), Ok(100_010_000)));
+    assert_eq!(fold.fold_par(&[]), (Ok(0), Ok(0)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn try_fold_par_fails() {
+    use std::vec::Vec;
+
+    use crate::num::Sum;
+
+    let xs: Vec<u64> = (1..=10_000).collect();
+    let mut fold = Sum::wrapping()
+        .map_err(|err| match err {})
+        .try_premap(|x: u64| if x == 5000 { Err(x) } else { Ok(x) });
+    assert_eq!(fold.try_fold_par(&xs), Err(5000));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn try_fold_par_returns_first_error() {
+    use std::vec::Vec;
+
+    use crate::num::Sum;
+
+    let xs: Vec<u64> = (1..=10_000).collect();
+    let mut fold = Sum::wrapping()
+        .map_err(|err| match err {})
+        .try_premap(|x: u64| {
+            if x.is_multiple_of(3000) {
+                Err(x)
+            } else {
+                Ok(x)
+            }
+        });
+    for _ in 0..20 {
+        assert_eq!(fold.try_fold_par(&xs), Err(3000));
+    }
+}
@@ -0,0 +1,1056 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Sub};
+use core::time::Duration;
+
+use crate::{Fold, Merge};
+
+/// The integers and floats that [`Sum`] and [`Product`] fold.
+pub trait Number: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Whether every product with `self` is `self`, so that a product can
+    /// stop early.
+    fn absorbs(self) -> bool;
+}
+
+macro_rules! number_impls {
+    ($($T:ty)*) => {$(
+        impl Number for $T {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn absorbs(self) -> bool { self == 0 }
+        }
+    )*};
+}
+
+number_impls!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+macro_rules! float_number_impls {
+    ($($T:ty)*) => {$(
+        impl Number for $T {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+
+            // Zero times an infinity or NaN is NaN, so no float absorbs.
+            fn absorbs(self) -> bool { false }
+        }
+    )*};
+}
+
+float_number_impls!(f32 f64);
+
+pub trait Integer: Number + Ord {
+    #[must_use]
+    fn wrapping_add(self, rhs: Self) -> Self;
+    #[must_use]
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    #[must_use]
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    #[must_use]
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    #[must_use]
+    fn saturating_add(self, rhs: Self) -> Self;
+    #[must_use]
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! integer_impls {
+    ($($T:ty)*) => {$(
+        impl Integer for $T {
+            fn wrapping_add(self, rhs: Self) -> Self { <$T>::wrapping_add(self, rhs) }
+            fn wrapping_mul(self, rhs: Self) -> Self { <$T>::wrapping_mul(self, rhs) }
+            fn checked_add(self, rhs: Self) -> Option<Self> { <$T>::checked_add(self, rhs) }
+            fn checked_mul(self, rhs: Self) -> Option<Self> { <$T>::checked_mul(self, rhs) }
+            fn saturating_add(self, rhs: Self) -> Self { <$T>::saturating_add(self, rhs) }
+            fn saturating_mul(self, rhs: Self) -> Self { <$T>::saturating_mul(self, rhs) }
+        }
+    )*};
+}
+
+integer_impls!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Implemented only for `f32` and `f64`, so that `sqrt` can be required
+/// whenever the `std` feature is enabled anywhere in the build.
+pub trait Float:
+    private::Sealed
+    + Number
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    #[must_use]
+    fn from_count(count: u64) -> Self;
+
+    #[must_use]
+    fn abs(self) -> Self;
+
+    #[cfg(feature = "std")]
+    #[must_use]
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! float_impls {
+    ($($T:ident)*) => {$(
+        impl private::Sealed for $T {}
+
+        impl Float for $T {
+            #[allow(clippy::cast_precision_loss)]
+            fn from_count(count: u64) -> Self { count as $T }
+
+            fn abs(self) -> Self { $T::abs(self) }
+
+            #[cfg(feature = "std")]
+            fn sqrt(self) -> Self { $T::sqrt(self) }
+        }
+    )*};
+}
+
+float_impls!(f32 f64);
+
+/// A lossy conversion to `f64`, for folds that summarize values as floats.
+/// Integers beyond 2^53 round to the nearest `f64`, and durations convert
+/// to seconds.
+pub trait ToF64 {
+    #[must_use]
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! to_f64_impls {
+    ($($T:ty)*) => {$(
+        impl ToF64 for $T {
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+            fn to_f64(self) -> f64 { self as f64 }
+        }
+    )*};
+}
+
+to_f64_impls!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+impl ToF64 for Duration {
+    fn to_f64(self) -> f64 { self.as_secs_f64() }
+}
+
+/// A total order on numbers, which unlike `Ord` includes floats, ordered by
+/// `total_cmp`.
+pub trait TotalOrd {
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! total_ord_impls {
+    ($($T:ty)*) => {$(
+        impl TotalOrd for $T {
+            fn total_cmp(&self, other: &Self) -> Ordering { self.cmp(other) }
+        }
+    )*};
+}
+
+total_ord_impls!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize Duration);
+
+impl TotalOrd for f32 {
+    fn total_cmp(&self, other: &Self) -> Ordering { Self::total_cmp(self, other) }
+}
+
+impl TotalOrd for f64 {
+    fn total_cmp(&self, other: &Self) -> Ordering { Self::total_cmp(self, other) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str("arithmetic overflow") }
+}
+
+impl Error for Overflow {}
+
+/// How [`Sum`], [`Product`] and [`Count`] handle overflow, or for floats,
+/// rounding.
+pub trait Arithmetic<T> {
+    type Error;
+
+    fn add(lhs: T, rhs: T) -> Result<T, Self::Error>;
+    fn mul(lhs: T, rhs: T) -> Result<T, Self::Error>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Wrapping;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Checked;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Saturating;
+
+/// Float arithmetic, which rounds instead of overflowing.
+#[derive(Debug, Clone, Copy)]
+pub struct Rounding;
+
+impl<T: Integer> Arithmetic<T> for Wrapping {
+    type Error = Infallible;
+
+    fn add(lhs: T, rhs: T) -> Result<T, Self::Error> { Ok(lhs.wrapping_add(rhs)) }
+    fn mul(lhs: T, rhs: T) -> Result<T, Self::Error> { Ok(lhs.wrapping_mul(rhs)) }
+}
+
+impl<T: Integer> Arithmetic<T> for Checked {
+    type Error = Overflow;
+
+    fn add(lhs: T, rhs: T) -> Result<T, Self::Error> { lhs.checked_add(rhs).ok_or(Overflow) }
+    fn mul(lhs: T, rhs: T) -> Result<T, Self::Error> { lhs.checked_mul(rhs).ok_or(Overflow) }
+}
+
+impl<T: Integer> Arithmetic<T> for Saturating {
+    type Error = Infallible;
+
+    fn add(lhs: T, rhs: T) -> Result<T, Self::Error> { Ok(lhs.saturating_add(rhs)) }
+    fn mul(lhs: T, rhs: T) -> Result<T, Self::Error> { Ok(lhs.saturating_mul(rhs)) }
+}
+
+impl<T: Float> Arithmetic<T> for Rounding {
+    type Error = Infallible;
+
+    fn add(lhs: T, rhs: T) -> Result<T, Self::Error> { Ok(lhs + rhs) }
+    fn mul(lhs: T, rhs: T) -> Result<T, Self::Error> { Ok(lhs * rhs) }
+}
+
+macro_rules! arithmetic_constructors {
+    ($Name:ident<$($P:ident),*>) => {
+        impl<$($P),*> $Name<$($P,)* Wrapping> {
+            #[must_use]
+            pub const fn wrapping() -> Self { Self { phantom: PhantomData } }
+        }
+
+        impl<$($P),*> $Name<$($P,)* Checked> {
+            #[must_use]
+            pub const fn checked() -> Self { Self { phantom: PhantomData } }
+        }
+
+        impl<$($P),*> $Name<$($P,)* Saturating> {
+            #[must_use]
+            pub const fn saturating() -> Self { Self { phantom: PhantomData } }
+        }
+
+        impl<$($P,)* A> Clone for $Name<$($P,)* A> {
+            fn clone(&self) -> Self { Self { phantom: PhantomData } }
+        }
+    };
+}
+
+pub struct Sum<T, A = Wrapping> {
+    phantom: PhantomData<fn(T) -> A>,
+}
+
+arithmetic_constructors!(Sum<T>);
+
+impl<T> Sum<T, Rounding> {
+    #[must_use]
+    pub const fn rounding() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Number, A: Arithmetic<T>> Fold for Sum<T, A> {
+    type Accumulator = T;
+    type Output = T;
+    type Error = A::Error;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator { T::ZERO }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        A::add(acc, elem)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
+}
+
+// Only wrapping arithmetic is associative: checked and saturating results
+// can depend on where the elements are split into chunks.
+impl<T: Integer> Merge for Sum<T, Wrapping> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok(acc1.wrapping_add(acc2))
+    }
+}
+
+impl<T: Float> Merge for Sum<T, Rounding> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok(acc1 + acc2)
+    }
+}
+
+pub struct Product<T, A = Wrapping> {
+    phantom: PhantomData<fn(T) -> A>,
+}
+
+arithmetic_constructors!(Product<T>);
+
+impl<T> Product<T, Rounding> {
+    #[must_use]
+    pub const fn rounding() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Number, A: Arithmetic<T>> Fold for Product<T, A> {
+    type Accumulator = T;
+    type Output = T;
+    type Error = A::Error;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator { T::ONE }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        A::mul(acc, elem)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
+
+    // An integer zero absorbs every later factor, so none of them can
+    // overflow.
+    fn is_done(&self, acc: &Self::Accumulator) -> bool { acc.absorbs() }
+}
+
+// As for `Sum`, only wrapping arithmetic merges independently of chunking.
+impl<T: Integer> Merge for Product<T, Wrapping> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok(acc1.wrapping_mul(acc2))
+    }
+}
+
+impl<T: Float> Merge for Product<T, Rounding> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok(acc1 * acc2)
+    }
+}
+
+pub struct Count<E, T = usize, A = Wrapping> {
+    phantom: PhantomData<fn(E, T) -> A>,
+}
+
+arithmetic_constructors!(Count<E, T>);
+
+impl<E> Count<E> {
+    /// Counts in a wrapping `usize`.
+    #[must_use]
+    pub const fn new() -> Self { Self::wrapping() }
+}
+
+impl<E> Default for Count<E> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<E, T: Integer, A: Arithmetic<T>> Fold for Count<E, T, A> {
+    type Accumulator = T;
+    type Output = T;
+    type Error = A::Error;
+    type Element = E;
+
+    fn init(&mut self) -> Self::Accumulator { T::ZERO }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        _elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        A::add(acc, T::ONE)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
+}
+
+impl<E, T: Integer, A: Arithmetic<T>> Merge for Count<E, T, A> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        A::add(acc1, acc2)
+    }
+}
+
+/// How [`Min`], [`Max`] and [`MinMax`] compare elements.
+pub trait Order<T> {
+    fn cmp(lhs: &T, rhs: &T) -> Ordering;
+}
+
+/// Compares by `Ord`.
+#[derive(Debug, Clone, Copy)]
+pub struct NaturalOrder;
+
+/// Compares by [`TotalOrd`], which also covers floats.
+#[derive(Debug, Clone, Copy)]
+pub struct TotalOrder;
+
+impl<T: Ord> Order<T> for NaturalOrder {
+    fn cmp(lhs: &T, rhs: &T) -> Ordering { lhs.cmp(rhs) }
+}
+
+impl<T: TotalOrd> Order<T> for TotalOrder {
+    fn cmp(lhs: &T, rhs: &T) -> Ordering { lhs.total_cmp(rhs) }
+}
+
+macro_rules! order_constructors {
+    ($Name:ident) => {
+        impl<T> $Name<T> {
+            #[must_use]
+            pub const fn new() -> Self {
+                Self {
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<T> $Name<T, TotalOrder> {
+            /// Compares by [`TotalOrd`], so that floats order by `total_cmp`.
+            #[must_use]
+            pub const fn total_order() -> Self {
+                Self {
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<T> Default for $Name<T> {
+            fn default() -> Self { Self::new() }
+        }
+
+        impl<T, O> Clone for $Name<T, O> {
+            fn clone(&self) -> Self {
+                Self {
+                    phantom: PhantomData,
+                }
+            }
+        }
+    };
+}
+
+// Ties keep the first minimum and the last maximum, like `Iterator::min` and
+// `Iterator::max`. Merging treats `acc1` as coming before `acc2`.
+fn min_by<T>(acc: Option<T>, elem: T, compare: impl FnOnce(&T, &T) -> Ordering) -> T {
+    match acc {
+        Some(acc) if compare(&acc, &elem).is_le() => acc,
+        _ => elem,
+    }
+}
+
+fn max_by<T>(acc: Option<T>, elem: T, compare: impl FnOnce(&T, &T) -> Ordering) -> T {
+    match acc {
+        Some(acc) if compare(&acc, &elem).is_gt() => acc,
+        _ => elem,
+    }
+}
+
+fn merge_by<T>(
+    acc1: Option<T>,
+    acc2: Option<T>,
+    step: impl FnOnce(Option<T>, T) -> T,
+) -> Option<T> {
+    match acc2 {
+        Some(elem) => Some(step(acc1, elem)),
+        None => acc1,
+    }
+}
+
+macro_rules! ord_fold {
+    ($Name:ident, $by:ident) => {
+        pub struct $Name<T, O = NaturalOrder> {
+            phantom: PhantomData<fn(T) -> O>,
+        }
+
+        order_constructors!($Name);
+
+        impl<T, O: Order<T>> Fold for $Name<T, O> {
+            type Accumulator = Option<T>;
+            type Output = Option<T>;
+            type Error = Infallible;
+            type Element = T;
+
+            fn init(&mut self) -> Self::Accumulator { None }
+
+            fn try_step(
+                &mut self,
+                acc: Self::Accumulator,
+                elem: Self::Element,
+            ) -> Result<Self::Accumulator, Self::Error> {
+                Ok(Some($by(acc, elem, O::cmp)))
+            }
+
+            fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
+        }
+
+        impl<T, O: Order<T>> Merge for $Name<T, O> {
+            fn try_merge(
+                &mut self,
+                acc1: Self::Accumulator,
+                acc2: Self::Accumulator,
+            ) -> Result<Self::Accumulator, Self::Error> {
+                Ok(merge_by(acc1, acc2, |acc, elem| $by(acc, elem, O::cmp)))
+            }
+        }
+    };
+}
+
+ord_fold!(Min, min_by);
+ord_fold!(Max, max_by);
+
+macro_rules! by_fold {
+    ($Name:ident, $by:ident) => {
+        #[derive(Clone)]
+        pub struct $Name<T, Fun> {
+            compare: Fun,
+            phantom: PhantomData<fn(T) -> T>,
+        }
+
+        impl<T, Fun> $Name<T, Fun> {
+            #[must_use]
+            pub const fn new(compare: Fun) -> Self {
+                Self {
+                    compare,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<T, Fun> Fold for $Name<T, Fun>
+        where
+            Fun: FnMut(&T, &T) -> Ordering,
+        {
+            type Accumulator = Option<T>;
+            type Output = Option<T>;
+            type Error = Infallible;
+            type Element = T;
+
+            fn init(&mut self) -> Self::Accumulator { None }
+
+            fn try_step(
+                &mut self,
+                acc: Self::Accumulator,
+                elem: Self::Element,
+            ) -> Result<Self::Accumulator, Self::Error> {
+                Ok(Some($by(acc, elem, &mut self.compare)))
+            }
+
+            fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
+        }
+
+        impl<T, Fun> Merge for $Name<T, Fun>
+        where
+            Fun: FnMut(&T, &T) -> Ordering,
+        {
+            fn try_merge(
+                &mut self,
+                acc1: Self::Accumulator,
+                acc2: Self::Accumulator,
+            ) -> Result<Self::Accumulator, Self::Error> {
+                let compare = &mut self.compare;
+                Ok(merge_by(acc1, acc2, |acc, elem| $by(acc, elem, compare)))
+            }
+        }
+    };
+}
+
+by_fold!(MinBy, min_by);
+by_fold!(MaxBy, max_by);
+
+macro_rules! by_key_fold {
+    ($Name:ident, $by:ident) => {
+        #[derive(Clone)]
+        pub struct $Name<T, Fun> {
+            key: Fun,
+            phantom: PhantomData<fn(T) -> T>,
+        }
+
+        impl<T, Fun> $Name<T, Fun> {
+            #[must_use]
+            pub const fn new(key: Fun) -> Self {
+                Self {
+                    key,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        // The accumulator caches the key of the current best element.
+        impl<T, K, Fun> Fold for $Name<T, Fun>
+        where
+            K: Ord,
+            Fun: FnMut(&T) -> K,
+        {
+            type Accumulator = Option<(K, T)>;
+            type Output = Option<T>;
+            type Error = Infallible;
+            type Element = T;
+
+            fn init(&mut self) -> Self::Accumulator { None }
+
+            fn try_step(
+                &mut self,
+                acc: Self::Accumulator,
+                elem: Self::Element,
+            ) -> Result<Self::Accumulator, Self::Error> {
+                let elem = ((self.key)(&elem), elem);
+                Ok(Some($by(acc, elem, |(k1, _), (k2, _)| k1.cmp(k2))))
+            }
+
+            fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { Some(acc?.1) }
+        }
+
+        impl<T, K, Fun> Merge for $Name<T, Fun>
+        where
+            K: Ord,
+            Fun: FnMut(&T) -> K,
+        {
+            fn try_merge(
+                &mut self,
+                acc1: Self::Accumulator,
+                acc2: Self::Accumulator,
+            ) -> Result<Self::Accumulator, Self::Error> {
+                Ok(merge_by(acc1, acc2, |acc, elem| {
+                    $by(acc, elem, |(k1, _), (k2, _)| k1.cmp(k2))
+                }))
+            }
+        }
+    };
+}
+
+by_key_fold!(MinByKey, min_by);
+by_key_fold!(MaxByKey, max_by);
+
+pub struct MinMax<T, O = NaturalOrder> {
+    phantom: PhantomData<fn(T) -> O>,
+}
+
+order_constructors!(MinMax);
+
+impl<T: Clone, O: Order<T>> Fold for MinMax<T, O> {
+    type Accumulator = Option<(T, T)>;
+    type Output = Option<(T, T)>;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator { None }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok(Some(match acc {
+            None => (elem.clone(), elem),
+            Some((min, max)) => {
+                if O::cmp(&elem, &min).is_lt() {
+                    (elem, max)
+                } else if O::cmp(&elem, &max).is_ge() {
+                    (min, elem)
+                } else {
+                    (min, max)
+                }
+            }
+        }))
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
+}
+
+impl<T: Clone, O: Order<T>> Merge for MinMax<T, O> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok(match (acc1, acc2) {
+            (Some((min1, max1)), Some((min2, max2))) => Some((
+                if O::cmp(&min2, &min1).is_lt() {
+                    min2
+                } else {
+                    min1
+                },
+                if O::cmp(&max1, &max2).is_gt() {
+                    max1
+                } else {
+                    max2
+                },
+            )),
+            (acc, None) | (None, acc) => acc,
+        })
+    }
+}
+
+stateless_fold!(KahanSum);
+stateless_fold!(NeumaierSum);
+#[cfg(feature = "alloc")]
+stateless_fold!(PairwiseSum);
+
+// The accumulator is the running sum and the low-order bits lost from it,
+// negated.
+fn kahan_step<T: Float>((sum, c): (T, T), elem: T) -> (T, T) {
+    let y = elem - c;
+    let t = sum + y;
+    (t, (t - sum) - y)
+}
+
+impl<T: Float> Fold for KahanSum<T> {
+    type Accumulator = (T, T);
+    type Output = T;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator { (T::ZERO, T::ZERO) }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok(kahan_step(acc, elem))
+    }
+
+    fn finish(&mut self, (sum, _): Self::Accumulator) -> Self::Output { sum }
+}
+
+impl<T: Float> Merge for KahanSum<T> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        (sum, c): Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok(kahan_step(kahan_step(acc1, sum), T::ZERO - c))
+    }
+}
+
+// The accumulator is the running sum and the low-order bits lost from it.
+// Unlike Kahan's, the compensation survives terms larger than the sum.
+fn neumaier_step<T: Float>((sum, c): (T, T), elem: T) -> (T, T) {
+    let t = sum + elem;
+    let lost = if sum.abs() >= elem.abs() {
+        (sum - t) + elem
+    } else {
+        (elem - t) + sum
+    };
+    (t, c + lost)
+}
+
+impl<T: Float> Fold for NeumaierSum<T> {
+    type Accumulator = (T, T);
+    type Output = T;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator { (T::ZERO, T::ZERO) }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok(neumaier_step(acc, elem))
+    }
+
+    fn finish(&mut self, (sum, c): Self::Accumulator) -> Self::Output { sum + c }
+}
+
+impl<T: Float> Merge for NeumaierSum<T> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        (sum, c): Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let (sum1, c1) = neumaier_step(acc1, sum);
+        Ok((sum1, c1 + c))
+    }
+}
+
+#[cfg(feature = "alloc")]
+const PAIRWISE_BLOCK: usize = 16;
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct PairwiseAccumulator<T> {
+    // The naive sum of the elements since the last complete block.
+    block: T,
+    block_len: usize,
+    // Bit `i` of `blocks` is set when `levels[i]` holds the sum of `2^i`
+    // complete blocks, so completing a block is a binary increment that
+    // sums runs of equal size.
+    levels: Vec<T>,
+    blocks: u64,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Float> PairwiseAccumulator<T> {
+    fn insert(&mut self, level: u32, mut sum: T) {
+        let mut i = level;
+        while self.blocks & (1 << i) != 0 {
+            sum = self.levels[i as usize] + sum;
+            i += 1;
+        }
+        if self.levels.len() <= i as usize {
+            self.levels.resize(i as usize + 1, T::ZERO);
+        }
+        self.levels[i as usize] = sum;
+        self.blocks += 1 << level;
+    }
+
+    fn add_to_block(&mut self, sum: T, len: usize) {
+        self.block = self.block + sum;
+        self.block_len += len;
+        if self.block_len >= PAIRWISE_BLOCK {
+            self.complete_block();
+        }
+    }
+
+    // Out of line, so that the per-element path stays a single addition.
+    #[cold]
+    fn complete_block(&mut self) {
+        self.insert(0, self.block);
+        (self.block, self.block_len) = (T::ZERO, 0);
+    }
+
+    fn levels(&self) -> impl Iterator<Item = (u32, T)> + '_ {
+        (0..)
+            .zip(&self.levels)
+            .filter(|(i, _)| self.blocks & (1 << i) != 0)
+            .map(|(i, &sum)| (i, sum))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Float> Fold for PairwiseSum<T> {
+    type Accumulator = PairwiseAccumulator<T>;
+    type Output = T;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator {
+        PairwiseAccumulator {
+            block: T::ZERO,
+            block_len: 0,
+            levels: Vec::new(),
+            blocks: 0,
+        }
+    }
+
+    fn try_step(
+        &mut self,
+        mut acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        acc.add_to_block(elem, 1);
+        Ok(acc)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        acc.levels().fold(acc.block, |sum, (_, level)| level + sum)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Float> Merge for PairwiseSum<T> {
+    fn try_merge(
+        &mut self,
+        mut acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        for (i, sum) in acc2.levels() {
+            acc1.insert(i, sum);
+        }
+        acc1.add_to_block(acc2.block, acc2.block_len);
+        Ok(acc1)
+    }
+}
+
+#[test]
+fn sums_and_products() {
+    let xs = [200_u8, 100];
+    assert_eq!(Sum::wrapping().fold(xs.into_iter()), 44);
+    assert_eq!(Sum::saturating().fold(xs.into_iter()), u8::MAX);
+    assert_eq!(Sum::checked().try_fold(xs.into_iter()), Err(Overflow));
+    assert_eq!(Sum::<u8, _>::checked().try_fold([1, 2].into_iter()), Ok(3));
+
+    let xs = [-100_i8, 2];
+    assert_eq!(Product::wrapping().fold(xs.into_iter()), 56);
+    assert_eq!(Product::saturating().fold(xs.into_iter()), i8::MIN);
+    assert_eq!(Product::checked().try_fold(xs.into_iter()), Err(Overflow));
+}
+
+#[test]
+fn product_stops_at_zero() {
+    let mut pulled = 0;
+    let xs = [3_u8, 0, 100, 100].into_iter().inspect(|_| pulled += 1);
+    assert_eq!(Product::checked().try_fold(xs), Ok(0));
+    assert_eq!(pulled, 2);
+}
+
+#[test]
+fn counts() {
+    assert_eq!(Count::new().fold("abc".chars()), 3);
+    assert_eq!(Count::<_, u8, _>::wrapping().fold(0..300), 44);
+    assert_eq!(Count::<_, u8, _>::saturating().fold(0..300), u8::MAX);
+    assert_eq!(Count::<_, u8, _>::checked().try_fold(0..300), Err(Overflow));
+}
+
+#[test]
+fn min_and_max() {
+    let xs = [(3, 'a'), (1, 'b'), (4, 'c'), (1, 'd'), (4, 'e')];
+    let by_first = |x: &(u32, char), y: &(u32, char)| x.0.cmp(&y.0);
+    let first = |x: &(u32, char)| x.0;
+    let mut fold = (Min::new(), Max::new(), MinMax::new());
+    assert_eq!(
+        fold.fold([3, 1, 4].into_iter()),
+        (Some(1), Some(4), Some((1, 4)))
+    );
+    let mut fold = (MinBy::new(by_first), MaxBy::new(by_first));
+    assert_eq!(fold.fold(xs.into_iter()), (Some((1, 'b')), Some((4, 'e'))));
+    let mut fold = (MinByKey::new(first), MaxByKey::new(first));
+    assert_eq!(fold.fold(xs.into_iter()), (Some((1, 'b')), Some((4, 'e'))));
+    assert_eq!(MinMax::<u32>::new().fold(core::iter::empty()), None);
+}
+
+#[test]
+fn float_folds() {
+    let xs = [2.5, -0.0, f64::NAN, 0.0, -4.0];
+    let mut fold = (
+        Min::total_order(),
+        Max::total_order(),
+        MinMax::total_order(),
+    );
+    let (min, max, min_max) = fold.fold(xs.into_iter());
+    assert_eq!(min, Some(-4.0));
+    assert!(max.unwrap().is_nan());
+    assert_eq!(min_max.map(|(min, _)| min), Some(-4.0));
+    let (_, max) = MinMax::total_order()
+        .fold([0.0_f64, -0.0].into_iter())
+        .unwrap();
+    assert!(max.is_sign_positive());
+
+    let mut fold = (Sum::rounding(), Product::rounding(), Count::new());
+    assert_eq!(fold.fold([1.5_f32, -2.0, 4.0].into_iter()), (3.5, -12.0, 3));
+    let chunks: [&[f64]; 2] = [&[1.5, -2.0], &[4.0]];
+    let mut fold = (Sum::rounding(), Product::rounding());
+    assert_eq!(crate::fold_chunks(&mut fold, chunks), (3.5, -12.0));
+}
+
+#[test]
+fn merge_keeps_order() {
+    let xs = [(3, 'a'), (1, 'b'), (4, 'c'), (1, 'd'), (4, 'e')];
+    let first = |x: &(u32, char)| x.0;
+    let mut fold = (
+        MinByKey::new(first),
+        MaxByKey::new(first),
+        Count::wrapping(),
+    );
+    for mid in 0..=xs.len() {
+        assert_eq!(
+            crate::fold_chunks(&mut fold, <[_; 2]>::from(xs.split_at(mid))),
+            (Some((1, 'b')), Some((4, 'e')), 5_usize)
+        );
+    }
+}
+
+// Summation errors in units of the roundoff `u` times the sum of absolute
+// values. Naive summation is bounded by `n - 1`, pairwise summation of
+// naive blocks of 16 by `15 + log2(n / 16)` and the compensated sums by 2,
+// up to second-order terms.
+#[cfg(all(test, feature = "alloc"))]
+fn sum_errors<I: Iterator<Item = f64>>(xs: impl Fn() -> I, exact: f64) -> [f64; 4] {
+    let scale = f64::EPSILON / 2.0 * xs().map(f64::abs).sum::<f64>();
+    let naive = xs().fold(0.0, |sum, x| sum + x);
+    let mut fold = (KahanSum::new(), NeumaierSum::new(), PairwiseSum::new());
+    let (kahan, neumaier, pairwise) = fold.fold(xs());
+    [naive, kahan, neumaier, pairwise].map(|sum| (sum - exact).abs() / scale)
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn repeated_tenths() {
+    // Scaling by a power of two is exact.
+    let exact = 0.1 * 1_048_576.0;
+    let [naive, kahan, neumaier, pairwise] =
+        sum_errors(|| core::iter::repeat_n(0.1, 1 << 20), exact);
+    assert!(naive > 1000.0);
+    assert!(kahan <= 2.0);
+    assert!(neumaier <= 2.0);
+    assert!(pairwise <= 20.0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn small_terms_after_large() {
+    // Every tiny term is lost against 1.0 when added one at a time.
+    let tiny = f64::EPSILON / 2.0;
+    let xs = || core::iter::once(1.0).chain(core::iter::repeat_n(tiny, 1 << 20));
+    let exact = 1.0 + tiny * f64::from(1 << 20);
+    let [naive, kahan, neumaier, pairwise] = sum_errors(xs, exact);
+    assert!(naive > 1000.0);
+    assert!(kahan <= 2.0);
+    assert!(neumaier <= 2.0);
+    assert!(pairwise <= 20.0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn cancelling_large_terms() {
+    let xs = [1.0, 1e100, 1.0, -1e100];
+    // Only Neumaier's compensation survives a term larger than the sum.
+    let [naive, kahan, neumaier, pairwise] = sum_errors(|| xs.into_iter(), 2.0);
+    assert!(naive > 0.0 && kahan > 0.0 && pairwise > 0.0);
+    assert!(neumaier <= f64::EPSILON);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn merge_float_sums() {
+    let xs: [f64; 7] = [0.1, 1e10, -0.3, 2.5e-7, -1e10, 0.7, 3.0];
+    let exact = 3.500_000_25;
+    let scale = f64::EPSILON / 2.0 * xs.iter().map(|x| x.abs()).sum::<f64>();
+    let mut fold = (KahanSum::new(), NeumaierSum::new(), PairwiseSum::new());
+    for mid in 0..=xs.len() {
+        let (kahan, neumaier, pairwise) =
+            crate::fold_chunks(&mut fold, <[_; 2]>::from(xs.split_at(mid)));
+        assert!((kahan - exact).abs() <= 2.0 * scale);
+        assert!((neumaier - exact).abs() <= 2.0 * scale);
+        assert!((pairwise - exact).abs() <= 3.0 * scale);
+    }
+}
@@ -0,0 +1,82 @@
+use rayon::iter::ParallelIterator;
+
+use crate::{Merge, Uninhabited};
+
+pub trait ParallelIteratorExt: ParallelIterator {
+    fn try_fold_par<F>(self, mut fold: F) -> Result<F::Output, F::Error>
+    where
+        F: Merge<Element = Self::Item> + Clone + Send + Sync,
+        F::Accumulator: Send,
+        F::Error: Send,
+    {
+        // Accumulators are initialised lazily, because rayon's identity
+        // closures cannot fail. `None` stands for the identity of `try_merge`.
+        let identity = || (fold.clone(), None);
+        let (_, acc) = self
+            .try_fold(identity, |(mut fold, acc), elem| {
+                let acc = match acc {
+                    Some(acc) => acc,
+                    None => fold.try_init()?,
+                };
+                if fold.is_done(&acc) {
+                    return Ok((fold, Some(acc)));
+                }
+                let acc = fold.try_step(acc, elem)?;
+                Ok((fold, Some(acc)))
+            })
+            .try_reduce(identity, |(mut fold, acc1), (_, acc2)| {
+                let acc = match (acc1, acc2) {
+                    (Some(acc1), Some(acc2)) => Some(fold.try_merge(acc1, acc2)?),
+                    (acc, None) | (None, acc) => acc,
+                };
+                Ok((fold, acc))
+            })?;
+        let acc = match acc {
+            Some(acc) => acc,
+            None => fold.try_init()?,
+        };
+        Ok(fold.finish(acc))
+    }
+
+    fn fold_par<F>(self, fold: F) -> F::Output
+    where
+        F: Merge<Element = Self::Item> + Clone + Send + Sync,
+        F::Accumulator: Send,
+        F::Error: Send + Uninhabited,
+    {
+        match self.try_fold_par(fold) {
+            Ok(acc) => acc,
+            Err(err) => err.absurd(),
+        }
+    }
+}
+
+impl<I: ParallelIterator> ParallelIteratorExt for I {}
+
+#[test]
+fn sum_and_evens() {
+    use rayon::iter::IntoParallelIterator;
+
+    use crate::num::Sum;
+    use crate::Fold;
+
+    let fold = Sum::wrapping().try_zip(Sum::wrapping().filter(|x| x % 2 == 0));
+    assert_eq!(
+        (1..=10_000_u64).into_par_iter().fold_par(fold),
+        (50_005_000, 25_005_000)
+    );
+}
+
+#[test]
+fn short_circuits() {
+    use rayon::iter::IntoParallelIterator;
+
+    use crate::num::Sum;
+    use crate::Fold;
+
+    let fold = Sum::wrapping()
+        .map_err(|err| match err {})
+        .try_premap(|x: u64| if x % 1000 == 999 { Err(x) } else { Ok(x) });
+    let res = (1..=10_000_u64).into_par_iter().try_fold_par(fold);
+    assert!(matches!(res, Err(x) if x % 1000 == 999));
+}
@@ -0,0 +1,380 @@
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::num::{Float, ToF64, TotalOrd};
+use crate::{Fold, Merge};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Empty;
+
+impl fmt::Display for Empty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str("no elements") }
+}
+
+impl Error for Empty {}
+
+/// How to pick a value when quantile `q` of `n` sorted elements falls
+/// between the elements at positions `floor(q (n - 1))` and
+/// `ceil(q (n - 1))`.
+pub trait Interpolation<T> {
+    type Output;
+
+    /// Picks the quantile from the `lower` element, the `higher` one and
+    /// the `fraction` of the way between them, where `lower` sits at
+    /// `position`.
+    fn interpolate<'a>(
+        &self,
+        lower: &'a T,
+        higher: impl FnOnce() -> &'a T,
+        fraction: f64,
+        position: usize,
+    ) -> Self::Output;
+}
+
+/// Interpolates linearly, as `f64`.
+#[derive(Debug, Clone, Copy)]
+pub struct Linear;
+
+/// Picks the closer element, or the one at the even position on a tie.
+#[derive(Debug, Clone, Copy)]
+pub struct Nearest;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Lower;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Higher;
+
+impl<T: Clone + ToF64> Interpolation<T> for Linear {
+    type Output = f64;
+
+    fn interpolate<'a>(
+        &self,
+        lower: &'a T,
+        higher: impl FnOnce() -> &'a T,
+        fraction: f64,
+        _position: usize,
+    ) -> Self::Output {
+        let x = lower.clone().to_f64();
+        if fraction <= 0.0 {
+            return x;
+        }
+        x + (higher().clone().to_f64() - x) * fraction
+    }
+}
+
+impl<T: Clone> Interpolation<T> for Nearest {
+    type Output = T;
+
+    fn interpolate<'a>(
+        &self,
+        lower: &'a T,
+        higher: impl FnOnce() -> &'a T,
+        fraction: f64,
+        position: usize,
+    ) -> Self::Output {
+        if fraction < 0.5 || (fraction <= 0.5 && position.is_multiple_of(2)) {
+            lower.clone()
+        } else {
+            higher().clone()
+        }
+    }
+}
+
+impl<T: Clone> Interpolation<T> for Lower {
+    type Output = T;
+
+    fn interpolate<'a>(
+        &self,
+        lower: &'a T,
+        _higher: impl FnOnce() -> &'a T,
+        _fraction: f64,
+        _position: usize,
+    ) -> Self::Output {
+        lower.clone()
+    }
+}
+
+impl<T: Clone> Interpolation<T> for Higher {
+    type Output = T;
+
+    fn interpolate<'a>(
+        &self,
+        lower: &'a T,
+        higher: impl FnOnce() -> &'a T,
+        fraction: f64,
+        _position: usize,
+    ) -> Self::Output {
+        if fraction <= 0.0 { lower } else { higher() }.clone()
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn floor(x: f64) -> usize { x as usize }
+
+// Selects quantile `q` of `elems[start..]`, where every element before
+// `start` is known to be no greater than the rest, and returns it together
+// with the position of its lower neighbour.
+fn select<T: TotalOrd, I: Interpolation<T>>(
+    elems: &mut [T],
+    start: usize,
+    q: f64,
+    method: &I,
+) -> (I::Output, usize) {
+    let last = elems.len() - 1;
+    let h = q.clamp(0.0, 1.0) * f64::from_count(last as u64);
+    let lower = floor(h).min(last).max(start);
+    let (_, x, rest) = elems[start..].select_nth_unstable_by(lower - start, T::total_cmp);
+    let x = &*x;
+    let higher = || rest.iter().min_by(|a, b| a.total_cmp(b)).unwrap_or(x);
+    let fraction = h - f64::from_count(lower as u64);
+    (method.interpolate(x, higher, fraction, lower), lower)
+}
+
+#[derive(Clone)]
+pub struct Quantile<T, I> {
+    q: f64,
+    method: I,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<T, I> Quantile<T, I> {
+    /// `q` is clamped to `[0, 1]`.
+    #[must_use]
+    pub const fn new(q: f64, method: I) -> Self {
+        Self {
+            q,
+            method,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: TotalOrd, I: Interpolation<T>> Fold for Quantile<T, I> {
+    type Accumulator = Vec<T>;
+    type Output = Result<I::Output, Empty>;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator { Vec::new() }
+
+    fn try_step(
+        &mut self,
+        mut acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        acc.push(elem);
+        Ok(acc)
+    }
+
+    fn finish(&mut self, mut acc: Self::Accumulator) -> Self::Output {
+        if acc.is_empty() {
+            return Err(Empty);
+        }
+        Ok(select(&mut acc, 0, self.q, &self.method).0)
+    }
+}
+
+impl<T: TotalOrd, I: Interpolation<T>> Merge for Quantile<T, I> {
+    fn try_merge(
+        &mut self,
+        mut acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        acc1.extend(acc2);
+        Ok(acc1)
+    }
+}
+
+#[derive(Clone)]
+pub struct Median<T, I> {
+    quantile: Quantile<T, I>,
+}
+
+impl<T, I> Median<T, I> {
+    #[must_use]
+    pub const fn new(method: I) -> Self {
+        Self {
+            quantile: Quantile::new(0.5, method),
+        }
+    }
+}
+
+impl<T: TotalOrd, I: Interpolation<T>> Fold for Median<T, I> {
+    type Accumulator = Vec<T>;
+    type Output = Result<I::Output, Empty>;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator { self.quantile.init() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.quantile.try_step(acc, elem)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { self.quantile.finish(acc) }
+}
+
+impl<T: TotalOrd, I: Interpolation<T>> Merge for Median<T, I> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.quantile.try_merge(acc1, acc2)
+    }
+}
+
+#[derive(Clone)]
+pub struct Quantiles<'a, T, I> {
+    qs: &'a [f64],
+    method: I,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<'a, T, I> Quantiles<'a, T, I> {
+    /// Each of `qs` is clamped to `[0, 1]`.
+    #[must_use]
+    pub const fn new(qs: &'a [f64], method: I) -> Self {
+        Self {
+            qs,
+            method,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: TotalOrd, I: Interpolation<T>> Fold for Quantiles<'_, T, I> {
+    type Accumulator = Vec<T>;
+    type Output = Result<Vec<I::Output>, Empty>;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator { Vec::new() }
+
+    fn try_step(
+        &mut self,
+        mut acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        acc.push(elem);
+        Ok(acc)
+    }
+
+    // Selecting the quantiles in increasing order leaves each selection a
+    // shorter suffix to partition.
+    fn finish(&mut self, mut acc: Self::Accumulator) -> Self::Output {
+        if acc.is_empty() {
+            return Err(Empty);
+        }
+        let mut order: Vec<usize> = (0..self.qs.len()).collect();
+        order.sort_unstable_by(|&i, &j| self.qs[i].total_cmp(&self.qs[j]));
+        let mut values = Vec::with_capacity(self.qs.len());
+        let mut start = 0;
+        for i in order {
+            let value;
+            (value, start) = select(&mut acc, start, self.qs[i], &self.method);
+            values.push((i, value));
+        }
+        values.sort_unstable_by_key(|&(i, _)| i);
+        Ok(values.into_iter().map(|(_, value)| value).collect())
+    }
+}
+
+impl<T: TotalOrd, I: Interpolation<T>> Merge for Quantiles<'_, T, I> {
+    fn try_merge(
+        &mut self,
+        mut acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        acc1.extend(acc2);
+        Ok(acc1)
+    }
+}
+
+#[test]
+fn interpolation_methods() {
+    let xs = || [7_u32, 1, 3, 5].into_iter();
+    assert_eq!(Median::new(Linear).fold(xs()), Ok(4.0));
+    assert_eq!(Median::new(Lower).fold(xs()), Ok(3));
+    assert_eq!(Median::new(Higher).fold(xs()), Ok(5));
+    // Position 1.5 is a tie, broken towards the even position 2.
+    assert_eq!(Median::new(Nearest).fold(xs()), Ok(5));
+    assert_eq!(Median::new(Linear).fold([9_u32, 2, 4].into_iter()), Ok(4.0));
+
+    assert_eq!(Quantile::new(0.1, Linear).fold(xs()), Ok(1.6));
+    assert_eq!(Quantile::new(0.1, Nearest).fold(xs()), Ok(1));
+    assert_eq!(Quantile::new(0.3, Nearest).fold(xs()), Ok(3));
+    assert_eq!(Quantile::new(0.1, Higher).fold(xs()), Ok(3));
+    assert_eq!(Quantile::new(0.0, Higher).fold(xs()), Ok(1));
+    assert_eq!(Quantile::new(1.0, Lower).fold(xs()), Ok(7));
+    assert_eq!(Quantile::new(2.0, Linear).fold(xs()), Ok(7.0));
+}
+
+#[test]
+fn exact_selection() {
+    use core::time::Duration;
+
+    let x = (1_u64 << 53) + 1;
+    assert_eq!(Median::new(Lower).fold([x, x, x].into_iter()), Ok(x));
+    assert_eq!(
+        Median::new(Nearest).fold([x - 1, x, x + 2].into_iter()),
+        Ok(x)
+    );
+    assert_eq!(
+        Quantile::new(0.0, Lower).fold([-3_i64, 9, -7].into_iter()),
+        Ok(-7)
+    );
+    assert_eq!(
+        Median::new(Linear).fold([u64::MAX, 0, 1 << 60].into_iter()),
+        Ok(2.0_f64.powi(60))
+    );
+
+    let xs = [3, 1, 2].map(Duration::from_nanos);
+    assert_eq!(
+        Median::new(Higher).fold(xs.into_iter()),
+        Ok(Duration::from_nanos(2))
+    );
+    assert_eq!(
+        Quantile::new(0.5, Lower).fold([0.5, f64::NAN, -0.0, 0.0].into_iter()),
+        Ok(0.0)
+    );
+}
+
+#[test]
+fn many_quantiles() {
+    let qs = [0.99, 0.0, 0.5, 0.25, 0.5, 1.0];
+    let mut fold = Quantiles::new(&qs, Linear);
+    let xs = (0..=100_u32).map(|i| i * 37 % 101);
+    assert_eq!(
+        fold.fold(xs),
+        Ok(std::vec![99.0, 0.0, 50.0, 25.0, 50.0, 100.0])
+    );
+    assert_eq!(fold.fold(core::iter::empty()), Err(Empty));
+}
+
+#[test]
+fn zip_with_min_and_max() {
+    use crate::num::{Max, Min};
+
+    let mut fold = Min::new().try_zip(Median::new(Linear)).try_zip(Max::new());
+    assert_eq!(
+        fold.fold([4_u32, 1, 8, 2].into_iter()),
+        ((Some(1), Ok(3.0)), Some(8))
+    );
+    assert_eq!(fold.fold(core::iter::empty()), ((None, Err(Empty)), None));
+}
+
+#[test]
+fn merge_buffers() {
+    let mut fold = Quantile::new(0.75, Lower);
+    let chunks: [&[u32]; 2] = [&[5, 3], &[9, 1, 7]];
+    assert_eq!(crate::fold_chunks(&mut fold, chunks), Ok(7));
+}
@@ -0,0 +1,107 @@
+use crate::Fold;
+
+pub struct Running<F: Fold> {
+    fold: F,
+    // Only `None` while a step is in progress, or after a step panicked.
+    state: Option<Result<F::Accumulator, F::Error>>,
+}
+
+impl<F: Fold> Running<F> {
+    pub fn new(mut fold: F) -> Self {
+        let state = Some(fold.try_init());
+        Self { fold, state }
+    }
+
+    const fn state(&self) -> &Result<F::Accumulator, F::Error> {
+        self.state.as_ref().expect("fold panicked during a step")
+    }
+
+    const fn take_state(&mut self) -> Result<F::Accumulator, F::Error> {
+        self.state.take().expect("fold panicked during a step")
+    }
+
+    fn try_update(
+        &mut self,
+        f: impl FnOnce(&mut F, F::Accumulator) -> Result<F::Accumulator, F::Error>,
+    ) -> Result<(), &F::Error> {
+        let state = self.take_state();
+        let state = self
+            .state
+            .insert(state.and_then(|acc| f(&mut self.fold, acc)));
+        state.as_ref().map(|_| ())
+    }
+
+    pub fn push(&mut self, elem: F::Element) -> Result<(), &F::Error> {
+        self.try_update(|fold, acc| {
+            if fold.is_done(&acc) {
+                Ok(acc)
+            } else {
+                fold.try_step(acc, elem)
+            }
+        })
+    }
+
+    pub fn push_many(
+        &mut self,
+        iter: impl IntoIterator<Item = F::Element>,
+    ) -> Result<(), &F::Error> {
+        self.try_update(|fold, acc| fold.try_accumulate(acc, iter.into_iter()))
+    }
+
+    pub fn is_done(&self) -> bool {
+        (self.state().as_ref()).map_or(true, |acc| self.fold.is_done(acc))
+    }
+
+    pub const fn peek(&self) -> Result<&F::Accumulator, &F::Error> { self.state().as_ref() }
+
+    pub fn snapshot(&self) -> Result<F::Accumulator, &F::Error>
+    where
+        F::Accumulator: Clone,
+    {
+        self.peek().cloned()
+    }
+
+    pub fn finish(mut self) -> Result<F::Output, F::Error> {
+        let state = self.take_state();
+        state.map(|acc| self.fold.finish(acc))
+    }
+}
+
+#[test]
+fn push_and_peek() {
+    use crate::from_fn;
+
+    let mut running = from_fn(0_u32, u32::wrapping_add).running();
+    assert_eq!(running.push(1), Ok(()));
+    assert_eq!(running.push_many([2, 3]), Ok(()));
+    assert_eq!(running.peek(), Ok(&6));
+    assert_eq!(running.snapshot(), Ok(6));
+    assert_eq!(running.push(4), Ok(()));
+    assert_eq!(running.finish(), Ok(10));
+}
+
+#[test]
+fn rejects_after_error() {
+    use crate::from_try_fn;
+
+    let sum = from_try_fn(0_u8, |x: u8, y| x.checked_add(y).ok_or(y));
+    let mut running = sum.running();
+    assert_eq!(running.push(200), Ok(()));
+    assert_eq!(running.push(100), Err(&100));
+    assert!(running.is_done());
+    assert_eq!(running.push(1), Err(&100));
+    assert_eq!(running.push_many([1, 2]), Err(&100));
+    assert_eq!(running.peek(), Err(&100));
+    assert_eq!(running.finish(), Err(100));
+}
+
+#[test]
+fn ignores_after_done() {
+    use crate::from_fn;
+
+    let mut running = from_fn(0_u32, u32::wrapping_add).take(2).running();
+    assert_eq!(running.push_many([1, 2]), Ok(()));
+    assert!(running.is_done());
+    assert_eq!(running.push(3), Ok(()));
+    assert_eq!(running.finish(), Ok(3));
+}
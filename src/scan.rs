@@ -0,0 +1,134 @@
+use core::iter::FusedIterator;
+
+use crate::{Fold, Uninhabited};
+
+pub struct TryScan<'a, F: Fold + ?Sized, I> {
+    fold: &'a mut F,
+    iter: I,
+    state: Option<Result<F::Accumulator, F::Error>>,
+}
+
+impl<'a, F: Fold + ?Sized, I> TryScan<'a, F, I> {
+    pub(crate) fn new(fold: &'a mut F, iter: I) -> Self {
+        let state = Some(fold.try_init());
+        Self { fold, iter, state }
+    }
+}
+
+impl<F, I> Iterator for TryScan<'_, F, I>
+where
+    F: Fold + ?Sized,
+    F::Accumulator: Clone,
+    I: Iterator<Item = F::Element>,
+{
+    type Item = Result<F::Accumulator, F::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let acc = match self.state.take()? {
+            Ok(acc) => acc,
+            Err(err) => return Some(Err(err)),
+        };
+        if self.fold.is_done(&acc) {
+            return None;
+        }
+        let elem = self.iter.next()?;
+        match self.fold.try_step(acc, elem) {
+            Ok(acc) => {
+                self.state = Some(Ok(acc.clone()));
+                Some(Ok(acc))
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.state {
+            None => (0, Some(0)),
+            Some(Err(_)) => (1, Some(1)),
+            Some(Ok(_)) => (0, self.iter.size_hint().1),
+        }
+    }
+}
+
+impl<F, I> FusedIterator for TryScan<'_, F, I>
+where
+    F: Fold + ?Sized,
+    F::Accumulator: Clone,
+    I: Iterator<Item = F::Element>,
+{
+}
+
+pub struct Scan<'a, F: Fold + ?Sized, I> {
+    inner: TryScan<'a, F, I>,
+}
+
+impl<'a, F: Fold + ?Sized, I> Scan<'a, F, I> {
+    pub(crate) fn new(fold: &'a mut F, iter: I) -> Self {
+        Self {
+            inner: TryScan::new(fold, iter),
+        }
+    }
+}
+
+impl<F, I> Iterator for Scan<'_, F, I>
+where
+    F: Fold + ?Sized,
+    F::Accumulator: Clone,
+    F::Error: Uninhabited,
+    I: Iterator<Item = F::Element>,
+{
+    type Item = F::Accumulator;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.next()? {
+            Ok(acc) => Some(acc),
+            Err(err) => err.absurd(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<F, I> FusedIterator for Scan<'_, F, I>
+where
+    F: Fold + ?Sized,
+    F::Accumulator: Clone,
+    F::Error: Uninhabited,
+    I: Iterator<Item = F::Element>,
+{
+}
+
+#[test]
+fn running_totals() {
+    use std::vec::Vec;
+
+    use crate::from_fn;
+
+    let mut sum = from_fn(0_u32, u32::wrapping_add);
+    let totals: Vec<_> = sum.scan([1, 2, 3, 4].into_iter()).collect();
+    assert_eq!(totals, [1, 3, 6, 10]);
+}
+
+#[test]
+fn scan_zip() {
+    use std::vec::Vec;
+
+    use crate::from_fn;
+
+    let sum = from_fn(0_u32, u32::wrapping_add);
+    let max = from_fn(0_u32, u32::max);
+    let mut fold = sum.try_zip(max).take(3);
+    let states: Vec<_> = fold.scan([3, 1, 4, 1, 5].into_iter()).collect();
+    assert_eq!(states, [(2, (3, 3)), (1, (4, 3)), (0, (8, 4))]);
+}
+
+#[test]
+fn try_scan_stops_at_error() {
+    use std::vec::Vec;
+
+    use crate::from_try_fn;
+
+    let mut sum = from_try_fn(0_u8, |x: u8, y| x.checked_add(y).ok_or(y));
+    let states: Vec<_> = sum.try_scan([100, 100, 100, 1].into_iter()).collect();
+    assert_eq!(states, [Ok(100), Ok(200), Err(100)]);
+}
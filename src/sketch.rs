@@ -0,0 +1,643 @@
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::marker::PhantomData;
+use core::mem;
+
+use crate::num::{Float, ToF64};
+use crate::{Fold, Merge};
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn floor(x: f64) -> u64 { x as u64 }
+
+// Piecewise-linear interpolation through `knots`, sorted by both
+// coordinates, evaluated at `x`.
+fn interpolate(knots: impl Iterator<Item = (f64, f64)>, x: f64) -> f64 {
+    let mut prev: Option<(f64, f64)> = None;
+    for (x1, y1) in knots {
+        match prev {
+            Some((x0, y0)) if x < x1 => {
+                return if x1 > x0 {
+                    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+                } else {
+                    y1
+                };
+            }
+            None if x < x1 => return y1,
+            _ => prev = Some((x1, y1)),
+        }
+    }
+    prev.map_or(f64::NAN, |(_, y)| y)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: u64,
+}
+
+/// A merging t-digest. Centroids near the median may absorb up to
+/// `4 n q (1 - q) / compression` elements, so the tails stay exact longer.
+#[derive(Clone)]
+pub struct TDigest<T> {
+    compression: f64,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<T> TDigest<T> {
+    /// Typical compressions are around 100; larger ones keep more centroids
+    /// and are more accurate.
+    #[must_use]
+    pub const fn new(compression: f64) -> Self {
+        Self {
+            compression: compression.max(1.0),
+            phantom: PhantomData,
+        }
+    }
+
+    const fn buffer_len(&self) -> usize {
+        #[allow(clippy::cast_possible_truncation)]
+        let len = floor(5.0 * self.compression) as usize;
+        len
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TDigestAccumulator {
+    // Sorted by mean, except in the middle of a merge.
+    centroids: Vec<Centroid>,
+    buffer: Vec<f64>,
+    count: u64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigestAccumulator {
+    fn compress(&mut self, compression: f64) {
+        let mut centroids = mem::take(&mut self.centroids);
+        let buffer = self.buffer.drain(..);
+        centroids.extend(buffer.map(|mean| Centroid { mean, weight: 1 }));
+        centroids.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let n = f64::from_count(self.count);
+        let mut centroids = centroids.into_iter();
+        let Some(mut current) = centroids.next() else {
+            return;
+        };
+        let mut before = 0;
+        for next in centroids {
+            let weight = current.weight + next.weight;
+            let q = (f64::from_count(before) + f64::from_count(weight) / 2.0) / n;
+            if f64::from_count(weight) <= 4.0 * n * q * (1.0 - q) / compression {
+                let share = f64::from_count(next.weight) / f64::from_count(weight);
+                current.mean += (next.mean - current.mean) * share;
+                current.weight = weight;
+            } else {
+                before += current.weight;
+                self.centroids.push(current);
+                current = next;
+            }
+        }
+        self.centroids.push(current);
+    }
+}
+
+impl<T: ToF64> Fold for TDigest<T> {
+    type Accumulator = TDigestAccumulator;
+    type Output = TDigestSummary;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator {
+        TDigestAccumulator {
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    fn try_step(
+        &mut self,
+        mut acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let elem = elem.to_f64();
+        acc.buffer.push(elem);
+        acc.count += 1;
+        acc.min = acc.min.min(elem);
+        acc.max = acc.max.max(elem);
+        if acc.buffer.len() >= self.buffer_len() {
+            acc.compress(self.compression);
+        }
+        Ok(acc)
+    }
+
+    fn finish(&mut self, mut acc: Self::Accumulator) -> Self::Output {
+        acc.compress(self.compression);
+        TDigestSummary {
+            centroids: acc.centroids,
+            count: acc.count,
+            min: acc.min,
+            max: acc.max,
+        }
+    }
+}
+
+impl<T: ToF64> Merge for TDigest<T> {
+    fn try_merge(
+        &mut self,
+        mut acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        acc1.centroids.extend(acc2.centroids);
+        acc1.buffer.extend(acc2.buffer);
+        acc1.count += acc2.count;
+        acc1.min = acc1.min.min(acc2.min);
+        acc1.max = acc1.max.max(acc2.max);
+        acc1.compress(self.compression);
+        Ok(acc1)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TDigestSummary {
+    centroids: Vec<Centroid>,
+    count: u64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigestSummary {
+    #[must_use]
+    pub const fn count(&self) -> u64 { self.count }
+
+    // The minimum at rank 0, each centroid at the rank of its middle and the
+    // maximum at rank n.
+    fn knots(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        let mut before = 0;
+        let centroids = self.centroids.iter().map(move |centroid| {
+            let rank = f64::from_count(before) + f64::from_count(centroid.weight) / 2.0;
+            before += centroid.weight;
+            (rank, centroid.mean)
+        });
+        let n = f64::from_count(self.count);
+        core::iter::once((0.0, self.min))
+            .chain(centroids)
+            .chain(core::iter::once((n, self.max)))
+    }
+
+    /// The estimated element at quantile `q`, or `None` for empty input.
+    #[must_use]
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        let n = f64::from_count(self.count);
+        (self.count > 0).then(|| interpolate(self.knots(), q.clamp(0.0, 1.0) * n))
+    }
+
+    /// The estimated fraction of elements less than or equal to `x`, or
+    /// `None` for empty input.
+    #[must_use]
+    pub fn rank(&self, x: f64) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        if x >= self.max {
+            return Some(1.0);
+        }
+        let knots = self.knots().map(|(rank, mean)| (mean, rank));
+        let n = f64::from_count(self.count);
+        Some(if x < self.min {
+            0.0
+        } else {
+            interpolate(knots, x) / n
+        })
+    }
+}
+
+/// A KLL sketch with deterministic compaction. Level `h` holds elements of
+/// weight `2^h`, and levels shrink by a factor of 2/3 from the top down to
+/// a capacity of 2.
+#[derive(Clone)]
+pub struct KllSketch<T> {
+    k: usize,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<T> KllSketch<T> {
+    /// Typical values of `k` are around 200, for a rank error of roughly
+    /// `1.7 / k`.
+    #[must_use]
+    pub const fn new(k: usize) -> Self {
+        Self {
+            k: if k < 2 { 2 } else { k },
+            phantom: PhantomData,
+        }
+    }
+
+    fn capacity(&self, level: usize, height: usize) -> usize {
+        (level + 1..height)
+            .fold(self.k, |capacity, _| capacity * 2 / 3)
+            .max(2)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KllAccumulator {
+    levels: Vec<Vec<f64>>,
+    // Bit `h` picks whether the next compaction of level `h` keeps the odd
+    // or the even elements.
+    offsets: u64,
+    count: u64,
+}
+
+impl KllAccumulator {
+    fn compress<T>(&mut self, sketch: &KllSketch<T>) {
+        loop {
+            let height = self.levels.len();
+            let capacity = (0..height).map(|h| sketch.capacity(h, height)).sum();
+            if self.levels.iter().map(Vec::len).sum::<usize>() <= capacity {
+                return;
+            }
+            let Some(level) =
+                (0..height).find(|&h| self.levels[h].len() > sketch.capacity(h, height))
+            else {
+                return;
+            };
+            if level + 1 == height {
+                self.levels.push(Vec::new());
+            }
+            let mut items = mem::take(&mut self.levels[level]);
+            items.sort_unstable_by(f64::total_cmp);
+            if items.len() % 2 == 1 {
+                self.levels[level].extend(items.pop());
+            }
+            let offset = usize::from(self.offsets & (1 << level) != 0);
+            self.offsets ^= 1 << level;
+            let promoted = items.into_iter().skip(offset).step_by(2);
+            self.levels[level + 1].extend(promoted);
+        }
+    }
+}
+
+impl<T: ToF64> Fold for KllSketch<T> {
+    type Accumulator = KllAccumulator;
+    type Output = KllSummary;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator {
+        KllAccumulator {
+            levels: Vec::new(),
+            offsets: 0,
+            count: 0,
+        }
+    }
+
+    fn try_step(
+        &mut self,
+        mut acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        if acc.levels.is_empty() {
+            acc.levels.push(Vec::new());
+        }
+        acc.levels[0].push(elem.to_f64());
+        acc.count += 1;
+        acc.compress(self);
+        Ok(acc)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        let mut items: Vec<_> = (acc.levels.into_iter().enumerate())
+            .flat_map(|(h, level)| level.into_iter().map(move |x| (x, 1_u64 << h)))
+            .collect();
+        items.sort_unstable_by(|(x, _), (y, _)| x.total_cmp(y));
+        let mut rank = 0;
+        for (_, weight) in &mut items {
+            rank += *weight;
+            *weight = rank;
+        }
+        KllSummary {
+            items,
+            count: acc.count,
+        }
+    }
+}
+
+impl<T: ToF64> Merge for KllSketch<T> {
+    fn try_merge(
+        &mut self,
+        mut acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        if acc1.levels.len() < acc2.levels.len() {
+            acc1.levels.resize_with(acc2.levels.len(), Vec::new);
+        }
+        for (level, items) in acc1.levels.iter_mut().zip(acc2.levels) {
+            level.extend(items);
+        }
+        acc1.count += acc2.count;
+        acc1.compress(self);
+        Ok(acc1)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KllSummary {
+    // Sorted by element, with the total weight up to and including each one.
+    items: Vec<(f64, u64)>,
+    count: u64,
+}
+
+impl KllSummary {
+    #[must_use]
+    pub const fn count(&self) -> u64 { self.count }
+
+    /// The estimated element at quantile `q`, or `None` for empty input.
+    #[must_use]
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        let target = floor(q.clamp(0.0, 1.0) * f64::from_count(self.count));
+        let i = self.items.partition_point(|&(_, rank)| rank <= target);
+        let (x, _) = self.items.get(i).or_else(|| self.items.last())?;
+        Some(*x)
+    }
+
+    /// The estimated fraction of elements less than or equal to `x`, or
+    /// `None` for empty input.
+    #[must_use]
+    pub fn rank(&self, x: f64) -> Option<f64> {
+        let i = self.items.partition_point(|(y, _)| y.total_cmp(&x).is_le());
+        let rank = i.checked_sub(1).map_or(0, |i| self.items[i].1);
+        (self.count > 0).then(|| f64::from_count(rank) / f64::from_count(self.count))
+    }
+}
+
+/// A Greenwald-Khanna summary.
+///
+/// Folded sequentially, its rank error is at most `epsilon * n`. Merging
+/// keeps every rank range valid but widens it, so a merged summary's error
+/// can grow by up to `epsilon * n` per level of merging.
+#[derive(Clone)]
+pub struct GreenwaldKhanna<T> {
+    epsilon: f64,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<T> GreenwaldKhanna<T> {
+    #[must_use]
+    pub const fn new(epsilon: f64) -> Self {
+        Self {
+            epsilon,
+            phantom: PhantomData,
+        }
+    }
+
+    fn band(&self, count: u64) -> u64 { floor(2.0 * self.epsilon * f64::from_count(count)) }
+
+    fn compress_period(&self) -> u64 { floor(1.0 / (2.0 * self.epsilon)).max(1) }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Tuple {
+    value: f64,
+    // The minimum rank of `value` minus that of the previous tuple.
+    g: u64,
+    // The maximum rank of `value` minus its minimum rank.
+    delta: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct GkAccumulator {
+    tuples: Vec<Tuple>,
+    count: u64,
+}
+
+impl GkAccumulator {
+    // Merges tuples into their right neighbour while the merged rank
+    // uncertainty stays within the band, keeping the minimum and maximum.
+    fn compress(&mut self, band: u64) {
+        let mut tuples: Vec<Tuple> = Vec::with_capacity(self.tuples.len());
+        for (i, tuple) in mem::take(&mut self.tuples).into_iter().enumerate().rev() {
+            match tuples.last_mut() {
+                Some(next) if i > 0 && tuple.g + next.g + next.delta <= band => next.g += tuple.g,
+                _ => tuples.push(tuple),
+            }
+        }
+        tuples.reverse();
+        self.tuples = tuples;
+    }
+}
+
+// Widens the rank uncertainty of `tuple` by that of the first tuple of the
+// other summary that comes after it.
+fn widen(tuple: Tuple, next: Option<&Tuple>) -> Tuple {
+    let delta = tuple.delta + next.map_or(0, |next| next.g + next.delta - 1);
+    Tuple { delta, ..tuple }
+}
+
+impl<T: ToF64> Fold for GreenwaldKhanna<T> {
+    type Accumulator = GkAccumulator;
+    type Output = GkSummary;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator {
+        GkAccumulator {
+            tuples: Vec::new(),
+            count: 0,
+        }
+    }
+
+    fn try_step(
+        &mut self,
+        mut acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let value = elem.to_f64();
+        let i = (acc.tuples).partition_point(|tuple| tuple.value.total_cmp(&value).is_le());
+        let delta = if i == 0 || i == acc.tuples.len() {
+            0
+        } else {
+            self.band(acc.count)
+        };
+        acc.tuples.insert(i, Tuple { value, g: 1, delta });
+        acc.count += 1;
+        if acc.count % self.compress_period() == 0 {
+            acc.compress(self.band(acc.count));
+        }
+        Ok(acc)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        let mut rank = 0;
+        let tuples = (acc.tuples.into_iter())
+            .map(|tuple| {
+                rank += tuple.g;
+                (tuple.value, rank, rank + tuple.delta)
+            })
+            .collect();
+        GkSummary {
+            tuples,
+            count: acc.count,
+        }
+    }
+}
+
+impl<T: ToF64> Merge for GreenwaldKhanna<T> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let (xs, ys) = (acc1.tuples, acc2.tuples);
+        let mut tuples = Vec::with_capacity(xs.len() + ys.len());
+        let (mut i, mut j) = (0, 0);
+        while i < xs.len() || j < ys.len() {
+            let first = match (xs.get(i), ys.get(j)) {
+                (Some(x), Some(y)) => x.value.total_cmp(&y.value).is_le(),
+                (x, _) => x.is_some(),
+            };
+            if first {
+                tuples.push(widen(xs[i], ys.get(j)));
+                i += 1;
+            } else {
+                tuples.push(widen(ys[j], xs.get(i)));
+                j += 1;
+            }
+        }
+        let mut acc = GkAccumulator {
+            tuples,
+            count: acc1.count + acc2.count,
+        };
+        acc.compress(self.band(acc.count));
+        Ok(acc)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GkSummary {
+    // Each element with its minimum and maximum rank.
+    tuples: Vec<(f64, u64, u64)>,
+    count: u64,
+}
+
+impl GkSummary {
+    #[must_use]
+    pub const fn count(&self) -> u64 { self.count }
+
+    /// The element whose rank range is closest to quantile `q`, or `None`
+    /// for empty input.
+    #[must_use]
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        let target = q.clamp(0.0, 1.0) * f64::from_count(self.count);
+        let distance = |&(_, rmin, rmax): &(f64, u64, u64)| {
+            let below = target - f64::from_count(rmin);
+            let above = f64::from_count(rmax) - target;
+            below.max(above)
+        };
+        let closest = (self.tuples.iter()).min_by(|x, y| distance(x).total_cmp(&distance(y)));
+        closest.map(|&(x, ..)| x)
+    }
+
+    /// The estimated fraction of elements less than or equal to `x`, or
+    /// `None` for empty input.
+    #[must_use]
+    pub fn rank(&self, x: f64) -> Option<f64> {
+        // The rank lies between the minimum rank of the last element not
+        // above `x` and the maximum rank of the next one, exclusive.
+        let i = (self.tuples).partition_point(|(y, ..)| y.total_cmp(&x).is_le());
+        let low = i.checked_sub(1).map_or(0, |i| self.tuples[i].1);
+        let high = (self.tuples.get(i)).map_or(self.count, |&(_, _, rmax)| rmax - 1);
+        let rank = f64::from_count(low + high) / 2.0;
+        (self.count > 0).then(|| rank / f64::from_count(self.count))
+    }
+}
+
+// A permutation of 0..10_000, as 7919 is coprime to 10_000.
+#[cfg(test)]
+fn shuffled() -> impl Iterator<Item = u32> + Clone { (0..10_000).map(|i| i * 7919 % 10_000) }
+
+#[cfg(test)]
+const fn sketches() -> (TDigest<u32>, KllSketch<u32>, GreenwaldKhanna<u32>) {
+    (
+        TDigest::new(100.0),
+        KllSketch::new(200),
+        GreenwaldKhanna::new(0.01),
+    )
+}
+
+#[cfg(test)]
+fn assert_accurate(tdigest: &TDigestSummary, kll: &KllSummary, gk: &GkSummary) {
+    for q in [0.0, 0.01, 0.25, 0.5, 0.95, 0.99, 1.0] {
+        let expected = q * 9_999.0;
+        let quantiles = [tdigest.quantile(q), kll.quantile(q), gk.quantile(q)];
+        for (quantile, tolerance) in quantiles.into_iter().zip([50.0, 100.0, 100.0]) {
+            let error = (quantile.unwrap() - expected).abs();
+            assert!(error <= tolerance, "quantile({q}) = {quantile:?}");
+        }
+        let ranks = [
+            tdigest.rank(expected),
+            kll.rank(expected),
+            gk.rank(expected),
+        ];
+        for (rank, tolerance) in ranks.into_iter().zip([0.005, 0.01, 0.01]) {
+            let error = (rank.unwrap() - q).abs();
+            assert!(error <= tolerance, "rank({expected}) = {rank:?}");
+        }
+    }
+}
+
+#[test]
+fn quantiles_and_ranks() {
+    let (tdigest, kll, gk) = sketches().fold(shuffled());
+    assert_eq!(
+        (tdigest.count(), kll.count(), gk.count()),
+        (10_000, 10_000, 10_000)
+    );
+    assert!(kll.items.len() < 1_000);
+    assert!(gk.tuples.len() < 1_000);
+    assert!(tdigest.centroids.len() < 1_000);
+    assert_accurate(&tdigest, &kll, &gk);
+}
+
+#[test]
+fn merge_chunks() {
+    use std::vec::Vec;
+
+    let xs: Vec<u32> = shuffled().collect();
+    let (tdigest, kll, gk) = crate::fold_chunks(&mut sketches(), xs.chunks(1_234));
+    assert_eq!(
+        (tdigest.count(), kll.count(), gk.count()),
+        (10_000, 10_000, 10_000)
+    );
+    assert_accurate(&tdigest, &kll, &gk);
+}
+
+#[test]
+fn empty() {
+    let (tdigest, kll, gk) = sketches().fold(core::iter::empty());
+    assert_eq!(tdigest.quantile(0.5), None);
+    assert_eq!(kll.quantile(0.5), None);
+    assert_eq!(gk.quantile(0.5), None);
+    assert_eq!(tdigest.rank(1.0), None);
+    assert_eq!(kll.rank(1.0), None);
+    assert_eq!(gk.rank(1.0), None);
+}
+
+#[test]
+fn u64_and_durations() {
+    use core::time::Duration;
+
+    let xs = shuffled().map(u64::from);
+    let (tdigest, kll, gk) = (
+        TDigest::new(100.0),
+        KllSketch::new(200),
+        GreenwaldKhanna::new(0.01),
+    )
+        .fold(xs.clone());
+    assert_accurate(&tdigest, &kll, &gk);
+
+    let millis = xs.map(Duration::from_millis);
+    let median = GreenwaldKhanna::new(0.01).fold(millis).quantile(0.5);
+    assert!((median.unwrap() - 5.0).abs() <= 0.1);
+}
@@ -0,0 +1,411 @@
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+use crate::num::Float;
+use crate::{Fold, Merge};
+
+// Welford's update and Chan's merge of the count and mean.
+fn step_mean<T: Float>((count, mean): (u64, T), elem: T) -> (u64, T) {
+    let count = count + 1;
+    (count, mean + (elem - mean) / T::from_count(count))
+}
+
+fn merge_mean<T: Float>((n1, mean1): (u64, T), (n2, mean2): (u64, T)) -> (u64, T) {
+    match (n1, n2) {
+        (_, 0) => (n1, mean1),
+        (0, _) => (n2, mean2),
+        _ => {
+            let count = n1 + n2;
+            let delta = mean2 - mean1;
+            (
+                count,
+                mean1 + delta * T::from_count(n2) / T::from_count(count),
+            )
+        }
+    }
+}
+
+stateless_fold!(Mean);
+
+impl<T: Float> Fold for Mean<T> {
+    type Accumulator = (u64, T);
+    type Output = Option<T>;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator { (0, T::ZERO) }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok(step_mean(acc, elem))
+    }
+
+    fn finish(&mut self, (count, mean): Self::Accumulator) -> Self::Output {
+        (count > 0).then_some(mean)
+    }
+}
+
+impl<T: Float> Merge for Mean<T> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok(merge_mean(acc1, acc2))
+    }
+}
+
+pub struct Variance<T> {
+    // Delta degrees of freedom: 0 for the population, 1 for a sample.
+    ddof: u64,
+    phantom: PhantomData<fn(T) -> T>,
+}
+
+impl<T> Variance<T> {
+    #[must_use]
+    pub const fn population() -> Self {
+        Self {
+            ddof: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub const fn sample() -> Self {
+        Self {
+            ddof: 1,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for Variance<T> {
+    fn clone(&self) -> Self {
+        Self {
+            ddof: self.ddof,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Float> Fold for Variance<T> {
+    /// The count, the mean and the sum of squared deviations from the mean.
+    type Accumulator = (u64, T, T);
+    type Output = Option<T>;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator { (0, T::ZERO, T::ZERO) }
+
+    fn try_step(
+        &mut self,
+        (count, mean, m2): Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let (count, new_mean) = step_mean((count, mean), elem);
+        Ok((count, new_mean, m2 + (elem - mean) * (elem - new_mean)))
+    }
+
+    fn finish(&mut self, (count, _, m2): Self::Accumulator) -> Self::Output {
+        (count > self.ddof).then(|| m2 / T::from_count(count - self.ddof))
+    }
+}
+
+impl<T: Float> Merge for Variance<T> {
+    fn try_merge(
+        &mut self,
+        (n1, mean1, m2_1): Self::Accumulator,
+        (n2, mean2, m2_2): Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let (count, mean) = merge_mean((n1, mean1), (n2, mean2));
+        if n1 == 0 || n2 == 0 {
+            return Ok((count, mean, m2_1 + m2_2));
+        }
+        let delta = mean2 - mean1;
+        let (n1, n2) = (T::from_count(n1), T::from_count(n2));
+        let m2 = m2_1 + m2_2 + delta * delta * n1 * n2 / T::from_count(count);
+        Ok((count, mean, m2))
+    }
+}
+
+#[cfg(feature = "std")]
+pub struct StdDev<T> {
+    variance: Variance<T>,
+}
+
+#[cfg(feature = "std")]
+impl<T> StdDev<T> {
+    #[must_use]
+    pub const fn population() -> Self {
+        Self {
+            variance: Variance::population(),
+        }
+    }
+
+    #[must_use]
+    pub const fn sample() -> Self {
+        Self {
+            variance: Variance::sample(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Clone for StdDev<T> {
+    fn clone(&self) -> Self {
+        Self {
+            variance: self.variance.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Float> Fold for StdDev<T> {
+    type Accumulator = (u64, T, T);
+    type Output = Option<T>;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator { self.variance.init() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.variance.try_step(acc, elem)
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        self.variance.finish(acc).map(T::sqrt)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Float> Merge for StdDev<T> {
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        self.variance.try_merge(acc1, acc2)
+    }
+}
+
+/// The count and mean of a sample together with the sums of the second,
+/// third and fourth powers of the deviations from the mean.
+#[derive(Debug, Clone, Copy)]
+pub struct CentralMoments<T> {
+    count: u64,
+    mean: T,
+    m2: T,
+    m3: T,
+    m4: T,
+}
+
+impl<T: Float> CentralMoments<T> {
+    #[must_use]
+    pub const fn count(&self) -> u64 { self.count }
+
+    #[must_use]
+    pub fn mean(&self) -> Option<T> { (self.count > 0).then_some(self.mean) }
+
+    #[must_use]
+    pub fn population_variance(&self) -> Option<T> {
+        (self.count > 0).then(|| self.m2 / T::from_count(self.count))
+    }
+
+    #[must_use]
+    pub fn sample_variance(&self) -> Option<T> {
+        (self.count > 1).then(|| self.m2 / T::from_count(self.count - 1))
+    }
+
+    /// The population skewness, `None` for empty input and NaN when all
+    /// elements are equal.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn skewness(&self) -> Option<T> {
+        let n = T::from_count(self.count);
+        (self.count > 0).then(|| n.sqrt() * self.m3 / (self.m2 * self.m2.sqrt()))
+    }
+
+    /// The population excess kurtosis, `None` for empty input and NaN when
+    /// all elements are equal.
+    #[must_use]
+    pub fn kurtosis(&self) -> Option<T> {
+        let n = T::from_count(self.count);
+        let three = T::from_count(3);
+        (self.count > 0).then(|| n * self.m4 / (self.m2 * self.m2) - three)
+    }
+}
+
+stateless_fold!(Moments);
+
+impl<T: Float> Fold for Moments<T> {
+    type Accumulator = CentralMoments<T>;
+    type Output = CentralMoments<T>;
+    type Error = Infallible;
+    type Element = T;
+
+    fn init(&mut self) -> Self::Accumulator {
+        CentralMoments {
+            count: 0,
+            mean: T::ZERO,
+            m2: T::ZERO,
+            m3: T::ZERO,
+            m4: T::ZERO,
+        }
+    }
+
+    // Pébay's single-element update of the third and fourth moments.
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        let CentralMoments {
+            count,
+            mean,
+            m2,
+            m3,
+            m4,
+        } = acc;
+        let (count, new_mean) = step_mean((count, mean), elem);
+        let n = T::from_count(count);
+        let c = |k| T::from_count(k);
+        let delta = elem - mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * (n - c(1));
+        Ok(CentralMoments {
+            count,
+            mean: new_mean,
+            m2: m2 + term,
+            m3: m3 + term * delta_n * (n - c(2)) - c(3) * delta_n * m2,
+            m4: m4 + term * delta_n2 * (n * n - c(3) * n + c(3)) + c(6) * delta_n2 * m2
+                - c(4) * delta_n * m3,
+        })
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output { acc }
+}
+
+impl<T: Float> Merge for Moments<T> {
+    // Chan's and Pébay's pairwise formulas.
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        if acc2.count == 0 {
+            return Ok(acc1);
+        }
+        if acc1.count == 0 {
+            return Ok(acc2);
+        }
+        let (count, mean) = merge_mean((acc1.count, acc1.mean), (acc2.count, acc2.mean));
+        let c = |k| T::from_count(k);
+        let (n1, n2, n) = (c(acc1.count), c(acc2.count), c(count));
+        let delta = acc2.mean - acc1.mean;
+        let delta2 = delta * delta;
+        let m2 = acc1.m2 + acc2.m2 + delta2 * n1 * n2 / n;
+        let m3 = acc1.m3
+            + acc2.m3
+            + delta2 * delta * n1 * n2 * (n1 - n2) / (n * n)
+            + c(3) * delta * (n1 * acc2.m2 - n2 * acc1.m2) / n;
+        let m4 = acc1.m4
+            + acc2.m4
+            + delta2 * delta2 * n1 * n2 * (n1 * (n1 - n2) + n2 * n2) / (n * n * n)
+            + c(6) * delta2 * (n1 * n1 * acc2.m2 + n2 * n2 * acc1.m2) / (n * n)
+            + c(4) * delta * (n1 * acc2.m3 - n2 * acc1.m3) / n;
+        Ok(CentralMoments {
+            count,
+            mean,
+            m2,
+            m3,
+            m4,
+        })
+    }
+}
+
+#[cfg(test)]
+fn assert_close(x: f64, y: f64) {
+    assert!((x - y).abs() <= 1e-9 * y.abs().max(1.0), "{x} != {y}");
+}
+
+#[test]
+fn mean_and_variance() {
+    let xs = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    let mut fold = (Mean::new(), Variance::population(), Variance::sample());
+    let (mean, population, sample) = fold.fold(xs.into_iter());
+    assert_eq!(mean, Some(5.0));
+    assert_eq!(population, Some(4.0));
+    assert_close(sample.unwrap(), 32.0 / 7.0);
+
+    let mut fold = (Mean::<f64>::new(), Variance::sample());
+    assert_eq!(fold.fold(core::iter::empty()), (None, None));
+    assert_eq!(fold.fold(core::iter::once(1.0)), (Some(1.0), None));
+}
+
+#[test]
+fn stable_with_large_offset() {
+    // Summing squares loses every significant digit of this variance.
+    let xs = [4.0, 7.0, 13.0, 16.0].map(|x| x + 1e9);
+    let naive: (f64, f64, f64) = xs.iter().fold((0.0, 0.0, 0.0), |(n, s, s2), x| {
+        (n + 1.0, s + x, s2 + x * x)
+    });
+    let naive = (naive.2 - naive.1 * naive.1 / naive.0) / (naive.0 - 1.0);
+    assert!((naive - 30.0).abs() > 1.0);
+    assert_eq!(Variance::sample().fold(xs.into_iter()), Some(30.0));
+}
+
+#[test]
+fn moments() {
+    let xs = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    let moments = Moments::new().fold(xs.into_iter());
+    assert_eq!(moments.count(), 8);
+    assert_eq!(moments.mean(), Some(5.0));
+    assert_close(moments.population_variance().unwrap(), 4.0);
+    assert_close(moments.sample_variance().unwrap(), 32.0 / 7.0);
+    // Deviations -3, -1, -1, -1, 0, 0, 2, 4.
+    assert_close(moments.kurtosis().unwrap(), 8.0 * 356.0 / 1024.0 - 3.0);
+    #[cfg(feature = "std")]
+    assert_close(
+        moments.skewness().unwrap(),
+        8_f64.sqrt() * 42.0 / 32_f64.powf(1.5),
+    );
+    assert_eq!(
+        Moments::<f64>::new().fold(core::iter::empty()).kurtosis(),
+        None
+    );
+}
+
+#[test]
+fn merge_matches_sequential() {
+    let xs = [3.5, -1.0, 4.25, 1.0, 5.5, 9.0, -2.5, 6.0, 5.0, 3.0];
+    let mut fold = (Mean::new(), Variance::sample(), Moments::new());
+    let (mean, variance, moments) = fold.fold(xs.into_iter());
+    for mid in 0..=xs.len() {
+        let (merged_mean, merged_variance, merged) =
+            crate::fold_chunks(&mut fold, <[_; 2]>::from(xs.split_at(mid)));
+        assert_close(merged_mean.unwrap(), mean.unwrap());
+        assert_close(merged_variance.unwrap(), variance.unwrap());
+        assert_eq!(merged.count(), moments.count());
+        assert_close(merged.m2, moments.m2);
+        assert_close(merged.m3, moments.m3);
+        assert_close(merged.m4, moments.m4);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn std_dev_with_count() {
+    use crate::num::Count;
+
+    let xs = [2.0_f64, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    let mut fold = StdDev::population().try_zip(Count::new());
+    assert_eq!(fold.fold(xs.into_iter()), (Some(2.0), 8));
+}
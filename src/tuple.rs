@@ -0,0 +1,159 @@
+use core::convert::Infallible;
+
+use crate::{Fold, Merge, ZipAll};
+
+macro_rules! tuple_impls {
+    ($(($($F:ident $idx:tt),+))+) => {$(
+        impl<Elem, Error, $($F),+> Fold for ($($F,)+)
+        where
+            $($F: Fold<Element = Elem, Error = Error>,)+
+            Elem: Clone,
+        {
+            type Accumulator = ($($F::Accumulator,)+);
+            type Output = ($($F::Output,)+);
+            type Error = Error;
+            type Element = Elem;
+
+            fn init(&mut self) -> Self::Accumulator { ($(self.$idx.init(),)+) }
+
+            fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> {
+                Ok(($(self.$idx.try_init()?,)+))
+            }
+
+            fn try_step(
+                &mut self,
+                acc: Self::Accumulator,
+                elem: Self::Element,
+            ) -> Result<Self::Accumulator, Self::Error> {
+                Ok(($(
+                    if self.$idx.is_done(&acc.$idx) {
+                        acc.$idx
+                    } else {
+                        self.$idx.try_step(acc.$idx, elem.clone())?
+                    },
+                )+))
+            }
+
+            fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+                ($(self.$idx.finish(acc.$idx),)+)
+            }
+
+            fn is_done(&self, acc: &Self::Accumulator) -> bool {
+                $(self.$idx.is_done(&acc.$idx))&&+
+            }
+        }
+
+        impl<Elem, Error, $($F),+> Merge for ($($F,)+)
+        where
+            $($F: Merge<Element = Elem, Error = Error>,)+
+            Elem: Clone,
+        {
+            fn try_merge(
+                &mut self,
+                acc1: Self::Accumulator,
+                acc2: Self::Accumulator,
+            ) -> Result<Self::Accumulator, Self::Error> {
+                Ok(($(self.$idx.try_merge(acc1.$idx, acc2.$idx)?,)+))
+            }
+        }
+
+        impl<Elem, $($F),+> Fold for ZipAll<($($F,)+)>
+        where
+            $($F: Fold<Element = Elem>,)+
+            Elem: Clone,
+        {
+            type Accumulator = ($(Result<$F::Accumulator, $F::Error>,)+);
+            type Output = ($(Result<$F::Output, $F::Error>,)+);
+            type Error = Infallible;
+            type Element = Elem;
+
+            fn init(&mut self) -> Self::Accumulator { ($(self.folds.$idx.try_init(),)+) }
+
+            fn try_step(
+                &mut self,
+                acc: Self::Accumulator,
+                elem: Self::Element,
+            ) -> Result<Self::Accumulator, Self::Error> {
+                Ok(($(
+                    match acc.$idx {
+                        Ok(acc) if !self.folds.$idx.is_done(&acc) => {
+                            self.folds.$idx.try_step(acc, elem.clone())
+                        }
+                        res => res,
+                    },
+                )+))
+            }
+
+            fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+                ($(acc.$idx.map(|acc| self.folds.$idx.finish(acc)),)+)
+            }
+
+            fn is_done(&self, acc: &Self::Accumulator) -> bool {
+                $(acc.$idx.as_ref().map_or(true, |acc| self.folds.$idx.is_done(acc)))&&+
+            }
+        }
+
+        impl<Elem, $($F),+> Merge for ZipAll<($($F,)+)>
+        where
+            $($F: Merge<Element = Elem>,)+
+            Elem: Clone,
+        {
+            fn try_merge(
+                &mut self,
+                acc1: Self::Accumulator,
+                acc2: Self::Accumulator,
+            ) -> Result<Self::Accumulator, Self::Error> {
+                Ok(($(
+                    acc1.$idx.and_then(|acc1| self.folds.$idx.try_merge(acc1, acc2.$idx?)),
+                )+))
+            }
+        }
+    )+};
+}
+
+tuple_impls! {
+    (F1 0)
+    (F1 0, F2 1)
+    (F1 0, F2 1, F3 2)
+    (F1 0, F2 1, F3 2, F4 3)
+    (F1 0, F2 1, F3 2, F4 3, F5 4)
+    (F1 0, F2 1, F3 2, F4 3, F5 4, F6 5)
+    (F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6)
+    (F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7)
+    (F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7, F9 8)
+    (F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7, F9 8, F10 9)
+    (F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7, F9 8, F10 9, F11 10)
+    (F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7, F9 8, F10 9, F11 10, F12 11)
+}
+
+#[test]
+fn flat_try_zip() {
+    use crate::{from_fn, from_try_fn};
+
+    let sum = from_fn(0_u32, u32::wrapping_add);
+    let prod = from_fn(1_u32, u32::wrapping_mul);
+    let count = from_fn(0_u32, |n, _| n + 1);
+    let max = from_fn(0_u32, u32::max);
+    let min = from_fn(u32::MAX, u32::min);
+    let mut fold = (sum, prod, count, max, min);
+    let xs = &[1, 2, 3, 4];
+    assert_eq!(fold.fold(xs.iter().copied()), (10, 24, 4, 4, 1));
+
+    let sum = from_try_fn(0_u8, |x: u8, y| x.checked_add(y).ok_or(()));
+    let prod = from_try_fn(1_u8, |x: u8, y| x.checked_mul(y).ok_or(()));
+    let mut fold = (sum, prod);
+    assert_eq!(fold.try_fold([1, 2, 3].into_iter()), Ok((6, 6)));
+    assert_eq!(fold.try_fold([10, 20, 30].into_iter()), Err(()));
+}
+
+#[test]
+fn flat_zip_all() {
+    use crate::{from_fn, from_try_fn, zip_all};
+
+    let sum = from_try_fn(0_u8, |x: u8, y| x.checked_add(y).ok_or("sum"));
+    let prod = from_try_fn(1_u8, |x: u8, y| x.checked_mul(y).ok_or(()));
+    let max = from_fn(0_u8, u8::max);
+    let mut fold = zip_all((sum, prod, max));
+    let xs = &[10, 20, 30];
+    assert_eq!(fold.fold(xs.iter().copied()), (Ok(60), Err(()), Ok(30)));
+}
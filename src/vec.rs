@@ -0,0 +1,138 @@
+use alloc::vec::Vec;
+use core::convert::Infallible;
+
+use crate::{Fold, Merge, ZipAll};
+
+impl<F> Fold for Vec<F>
+where
+    F: Fold,
+    F::Element: Clone,
+{
+    type Accumulator = Vec<F::Accumulator>;
+    type Output = Vec<F::Output>;
+    type Error = F::Error;
+    type Element = F::Element;
+
+    fn init(&mut self) -> Self::Accumulator { self.iter_mut().map(F::init).collect() }
+
+    fn try_init(&mut self) -> Result<Self::Accumulator, Self::Error> {
+        self.iter_mut().map(F::try_init).collect()
+    }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        (acc.into_iter().zip(self.iter_mut()))
+            .map(|(acc, fold)| {
+                if fold.is_done(&acc) {
+                    Ok(acc)
+                } else {
+                    fold.try_step(acc, elem.clone())
+                }
+            })
+            .collect()
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        (acc.into_iter().zip(self.iter_mut()))
+            .map(|(acc, fold)| fold.finish(acc))
+            .collect()
+    }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool {
+        self.iter().zip(acc).all(|(fold, acc)| fold.is_done(acc))
+    }
+}
+
+impl<F> Merge for Vec<F>
+where
+    F: Merge,
+    F::Element: Clone,
+{
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        (acc1.into_iter().zip(acc2).zip(self.iter_mut()))
+            .map(|((acc1, acc2), fold)| fold.try_merge(acc1, acc2))
+            .collect()
+    }
+}
+
+impl<F> Fold for ZipAll<Vec<F>>
+where
+    F: Fold,
+    F::Element: Clone,
+{
+    type Accumulator = Vec<Result<F::Accumulator, F::Error>>;
+    type Output = Vec<Result<F::Output, F::Error>>;
+    type Error = Infallible;
+    type Element = F::Element;
+
+    fn init(&mut self) -> Self::Accumulator { self.folds.iter_mut().map(F::try_init).collect() }
+
+    fn try_step(
+        &mut self,
+        acc: Self::Accumulator,
+        elem: Self::Element,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok((acc.into_iter().zip(self.folds.iter_mut()))
+            .map(|(acc, fold)| match acc {
+                Ok(acc) if !fold.is_done(&acc) => fold.try_step(acc, elem.clone()),
+                res => res,
+            })
+            .collect())
+    }
+
+    fn finish(&mut self, acc: Self::Accumulator) -> Self::Output {
+        (acc.into_iter().zip(self.folds.iter_mut()))
+            .map(|(acc, fold)| acc.map(|acc| fold.finish(acc)))
+            .collect()
+    }
+
+    fn is_done(&self, acc: &Self::Accumulator) -> bool {
+        (self.folds.iter().zip(acc))
+            .all(|(fold, acc)| acc.as_ref().map_or(true, |acc| fold.is_done(acc)))
+    }
+}
+
+impl<F> Merge for ZipAll<Vec<F>>
+where
+    F: Merge,
+    F::Element: Clone,
+{
+    fn try_merge(
+        &mut self,
+        acc1: Self::Accumulator,
+        acc2: Self::Accumulator,
+    ) -> Result<Self::Accumulator, Self::Error> {
+        Ok((acc1.into_iter().zip(acc2).zip(self.folds.iter_mut()))
+            .map(|((acc1, acc2), fold)| acc1.and_then(|acc1| fold.try_merge(acc1, acc2?)))
+            .collect())
+    }
+}
+
+#[test]
+fn columns() {
+    use std::vec;
+
+    use crate::{from_try_fn, zip_all};
+
+    let column_sum = |col: usize| {
+        from_try_fn(0_u8, move |x: u8, y| x.checked_add(y).ok_or(col))
+            .premap(move |row: [u8; 3]| row[col])
+    };
+    let rows = &[[1, 2, 100], [3, 4, 200]];
+
+    let mut fold: Vec<_> = (0..2).map(column_sum).collect();
+    assert_eq!(fold.try_fold(rows.iter().copied()), Ok(vec![4, 6]));
+
+    let mut fold: Vec<_> = (0..3).map(column_sum).collect();
+    assert_eq!(fold.try_fold(rows.iter().copied()), Err(2));
+
+    let mut fold = zip_all((0..3).map(column_sum).collect::<Vec<_>>());
+    assert_eq!(fold.fold(rows.iter().copied()), vec![Ok(4), Ok(6), Err(2)]);
+}